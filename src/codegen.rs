@@ -0,0 +1,785 @@
+//! Generate declarations in other languages from the types described by debug
+//! information, as returned by [DebugInfo::to_c_declaration] and
+//! [DebugInfo::to_rust_definition].
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::{
+    DebugInfo,
+    debug_types::DebugTypeError,
+    unit_info::{BaseType, BaseTypeEncoding, DebugItem, Enumeration, StructureMember},
+};
+
+/// Generate a C definition of the structure, union or enumeration `item`, preceded by the
+/// definitions of every type that it contains by value.
+pub(crate) fn c_declaration(info: &DebugInfo, item: DebugItem) -> Result<String, DebugTypeError> {
+    let mut generator = CGenerator {
+        target: Target {
+            info,
+            owner: info.full_qualified_name_from_item(item).unwrap_or_default(),
+            language: "C",
+        },
+        definitions: vec![],
+        defined: HashSet::new(),
+        forward: BTreeSet::new(),
+    };
+    let item = info.resolve_item(item);
+    if let Some(enumeration) = info.enumeration_from_item(item) {
+        generator.define_enumeration(item, enumeration, "")?;
+    } else {
+        generator.define_aggregate(item, "", "")?;
+    }
+
+    let mut output = String::from("#include <stdbool.h>\n#include <stdint.h>\n\n");
+    // Types that are only pointed to don't need a definition, just a name.
+    let forward: Vec<_> = generator
+        .forward
+        .iter()
+        .filter(|declaration| {
+            !generator
+                .definitions
+                .iter()
+                .any(|definition| definition.starts_with(&format!("{declaration} {{")))
+        })
+        .collect();
+    for declaration in &forward {
+        output.push_str(&format!("{declaration};\n"));
+    }
+    if !forward.is_empty() {
+        output.push('\n');
+    }
+    output.push_str(&generator.definitions.join("\n"));
+    Ok(output)
+}
+
+/// Generate a Rust definition of the structure, union or enumeration `item`, preceded by
+/// the definitions of every type that it contains.
+pub(crate) fn rust_definition(info: &DebugInfo, item: DebugItem) -> Result<String, DebugTypeError> {
+    let mut generator = RustGenerator {
+        target: Target {
+            info,
+            owner: info.full_qualified_name_from_item(item).unwrap_or_default(),
+            language: "Rust",
+        },
+        definitions: vec![],
+        defined: HashSet::new(),
+    };
+    let item = info.resolve_item(item);
+    if let Some(enumeration) = info.enumeration_from_item(item) {
+        generator.define_enumeration(item, enumeration, "")?;
+    } else {
+        generator.define_aggregate(item, "", "")?;
+    }
+    Ok(generator.definitions.join("\n"))
+}
+
+/// Turn a Rust or C++ type name such as `core::option::Option<u32>` into a C identifier
+/// such as `core_option_Option_u32`.
+fn c_identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    for character in name.chars() {
+        if character.is_ascii_alphanumeric() || character == '_' {
+            identifier.push(character);
+        } else if !identifier.is_empty() && !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+    }
+    identifier.trim_end_matches('_').to_owned()
+}
+
+/// Turn a type name such as `core::option::Option<u32>` into a Rust type name such as
+/// `CoreOptionOptionU32`.
+fn rust_type_identifier(name: &str) -> String {
+    name.split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut characters = word.chars();
+            characters
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + characters.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Turn a variant name such as `ReadyToSend` into a constant name such as
+/// `READY_TO_SEND`.
+fn rust_constant_identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    let mut previous: Option<char> = None;
+    for character in name.chars() {
+        if !character.is_ascii_alphanumeric() {
+            if !identifier.is_empty() && !identifier.ends_with('_') {
+                identifier.push('_');
+            }
+        } else {
+            if character.is_ascii_uppercase()
+                && previous.is_some_and(|previous| {
+                    previous.is_ascii_lowercase() || previous.is_ascii_digit()
+                })
+            {
+                identifier.push('_');
+            }
+            identifier.push(character.to_ascii_uppercase());
+        }
+        previous = Some(character);
+    }
+    if identifier.starts_with(|character: char| character.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    identifier
+}
+
+/// Turn a member name into a Rust field name, escaping any that are keywords.
+fn rust_field_identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in",
+        "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+        "return", "static", "struct", "trait", "true", "try", "type", "typeof", "union", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    let name = c_identifier(name);
+    if matches!(name.as_str(), "self" | "Self" | "super" | "crate" | "_") {
+        format!("{name}_")
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else {
+        name
+    }
+}
+
+/// Join a member path and a member name, for naming the member in errors.
+fn member_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+/// The name of a member, or its position if it has none.
+fn member_name(member: &StructureMember, index: usize) -> String {
+    member
+        .name()
+        .map(|name| name.to_owned())
+        .unwrap_or_else(|| format!("anonymous_{index}"))
+}
+
+/// The name of `item`, as passed through `convert`, or `fallback` for anonymous types.
+fn type_identifier(
+    info: &DebugInfo,
+    item: DebugItem,
+    fallback: &str,
+    convert: fn(&str) -> String,
+) -> String {
+    let identifier = convert(&info.full_qualified_name_from_item(item).unwrap_or_default());
+    if identifier.is_empty() {
+        fallback.to_owned()
+    } else {
+        identifier
+    }
+}
+
+/// The alignment that a compiler would give `item` in a `repr(C)` layout, used to spot
+/// packed types.
+fn alignment(info: &DebugInfo, item: DebugItem) -> u64 {
+    let item = info.resolve_item(item);
+    let members_alignment = |members: &[StructureMember]| {
+        members
+            .iter()
+            .map(|member| alignment(info, member.kind()))
+            .max()
+            .unwrap_or(1)
+    };
+    if let Some(array) = info.array_from_item(item) {
+        return alignment(info, array.kind());
+    }
+    if let Some(structure) = info.structure_from_item(item) {
+        return members_alignment(structure.members());
+    }
+    if let Some(union) = info.union_from_item(item) {
+        return members_alignment(union.members());
+    }
+    if let Some(enumeration) = info.enumeration_from_item(item) {
+        return alignment(info, enumeration.discriminant_kind());
+    }
+    info.byte_size(item).unwrap_or(1).clamp(1, 16)
+}
+
+/// Whether any variant of `enumeration` holds fields, rather than being just a value of
+/// the discriminant as in C.
+fn has_variants_with_fields(enumeration: &Enumeration) -> bool {
+    let discriminant = enumeration.discriminant_kind();
+    enumeration
+        .variants()
+        .iter()
+        .any(|variant| variant.kind() != discriminant)
+}
+
+/// Which language a declaration is being generated for, and the type it was asked for,
+/// so that errors can say what went wrong and where.
+struct Target<'a> {
+    info: &'a DebugInfo,
+    owner: String,
+    language: &'static str,
+}
+
+impl<'a> Target<'a> {
+    fn unrepresentable(&self, kind: DebugItem, path: &str, reason: &str) -> DebugTypeError {
+        DebugTypeError::Unrepresentable {
+            owner: self.owner.clone(),
+            kind: self
+                .info
+                .full_qualified_name_from_item(kind)
+                .unwrap_or_else(|| "<unknown>".to_owned()),
+            language: self.language,
+            reason: reason.into(),
+            path: path.to_owned(),
+            location: None,
+        }
+    }
+
+    /// Check that `enumeration` has no variants with fields, which neither language can
+    /// lay out the way Rust does, and return the base type of its discriminant.
+    fn discriminant_type(
+        &self,
+        item: DebugItem,
+        enumeration: &Enumeration,
+        path: &str,
+    ) -> Result<&'a BaseType, DebugTypeError> {
+        let discriminant = enumeration.discriminant_kind();
+        if has_variants_with_fields(enumeration) {
+            return Err(self.unrepresentable(item, path, "it has variants with fields"));
+        }
+        self.info
+            .base_type_from_item(self.info.resolve_item(discriminant))
+            .ok_or_else(|| {
+                self.unrepresentable(item, path, "its discriminant isn't a plain integer")
+            })
+    }
+}
+
+/// One entry in the body of a generated structure or union.
+enum Field {
+    Member {
+        name: String,
+        kind: DebugItem,
+    },
+    /// A member that takes up no space, such as a `PhantomData`, which is left out.
+    ZeroSized(String),
+    /// Bytes that the compiler left unused.
+    Padding(u64),
+}
+
+/// The body of a generated structure or union.
+struct Fields {
+    fields: Vec<Field>,
+    /// Whether a member is placed at an offset that its alignment doesn't allow.
+    packed: bool,
+}
+
+/// Work out the fields of a structure or union of `size` bytes, in order of their
+/// offset and with padding wherever there is a gap.
+fn plan_fields(
+    target: &Target<'_>,
+    members: &[StructureMember],
+    size: u64,
+    is_union: bool,
+    path: &str,
+) -> Result<Fields, DebugTypeError> {
+    let info = target.info;
+    let mut members: Vec<_> = members.iter().enumerate().collect();
+    members.sort_by_key(|(_, member)| member.offset().0);
+    let mut fields = vec![];
+    let mut end = 0;
+    let mut packed = false;
+    for (index, member) in members {
+        let name = member_name(member, index);
+        let offset = if is_union { 0 } else { member.offset().0 };
+        let Some(member_size) = info.byte_size(member.kind()) else {
+            return Err(target.unrepresentable(
+                member.kind(),
+                &member_path(path, &name),
+                "its size isn't known",
+            ));
+        };
+        if member_size == 0 {
+            fields.push(Field::ZeroSized(name));
+            continue;
+        }
+        if !is_union && offset < end {
+            return Err(target.unrepresentable(
+                member.kind(),
+                &member_path(path, &name),
+                "it overlaps the member before it",
+            ));
+        }
+        if offset > end {
+            fields.push(Field::Padding(offset - end));
+        }
+        packed |= offset % alignment(info, member.kind()) != 0;
+        fields.push(Field::Member {
+            name,
+            kind: member.kind(),
+        });
+        end = if is_union {
+            end.max(member_size)
+        } else {
+            offset + member_size
+        };
+    }
+    if size > end {
+        // A union that is bigger than all of its members gets a member that covers it.
+        fields.push(Field::Padding(if is_union { size } else { size - end }));
+    }
+    Ok(Fields { fields, packed })
+}
+
+/// The fixed-width C type with the same size and encoding as `base_type`.
+fn c_base_type(base_type: &BaseType) -> Option<&'static str> {
+    Some(match (base_type.encoding_kind(), base_type.size()) {
+        (BaseTypeEncoding::Boolean, 1) => "bool",
+        (BaseTypeEncoding::Float, 4) => "float",
+        (BaseTypeEncoding::Float, 8) => "double",
+        (BaseTypeEncoding::Signed | BaseTypeEncoding::SignedChar, size) => match size {
+            1 => "int8_t",
+            2 => "int16_t",
+            4 => "int32_t",
+            8 => "int64_t",
+            16 => "__int128",
+            _ => return None,
+        },
+        (
+            BaseTypeEncoding::Unsigned
+            | BaseTypeEncoding::UnsignedChar
+            | BaseTypeEncoding::Address
+            | BaseTypeEncoding::Utf,
+            size,
+        ) => match size {
+            1 => "uint8_t",
+            2 => "uint16_t",
+            4 => "uint32_t",
+            8 => "uint64_t",
+            16 => "unsigned __int128",
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
+
+struct CGenerator<'a> {
+    target: Target<'a>,
+    /// Complete definitions, each following the definitions it depends on.
+    definitions: Vec<String>,
+    /// Types that have a definition, so that each is only emitted once.
+    defined: HashSet<DebugItem>,
+    /// Forward declarations of types that are only used behind a pointer.
+    forward: BTreeSet<String>,
+}
+
+impl CGenerator<'_> {
+    /// Declare `name` as having the type `kind`, such as `uint8_t name[4]` or
+    /// `struct foo *name`. `name` may already be wrapped by an outer declarator, so an
+    /// array of pointers is declared by declaring `name[4]` as a pointer. Structures,
+    /// unions and enumerations that are used by value are defined along the way.
+    fn declare(
+        &mut self,
+        kind: DebugItem,
+        name: &str,
+        path: &str,
+        context: &str,
+    ) -> Result<String, DebugTypeError> {
+        let info = self.target.info;
+        let kind = info.resolve_item(kind);
+        if let Some(base_type) = info.base_type_from_item(kind) {
+            let Some(c_type) = c_base_type(base_type) else {
+                return Err(self.target.unrepresentable(
+                    kind,
+                    path,
+                    "there is no C type with the same size and encoding",
+                ));
+            };
+            return Ok(format!("{c_type} {name}"));
+        }
+        if let Some(pointer) = info.pointer_from_item(kind) {
+            let Some(pointee) = pointer.kind().map(|pointee| info.resolve_item(pointee)) else {
+                return Ok(format!("void *{name}"));
+            };
+            // Rust's `()` is a base type with no size, which C calls `void`.
+            if info
+                .base_type_from_item(pointee)
+                .is_some_and(|base_type| base_type.size() == 0)
+            {
+                return Ok(format!("void *{name}"));
+            }
+            if info.array_from_item(pointee).is_some()
+                || info.subroutine_type_from_item(pointee).is_some()
+            {
+                return self.declare(pointee, &format!("(*{name})"), path, context);
+            }
+            // Pointing to a structure or union doesn't need its definition, which also
+            // keeps self-referential types from recursing forever. Enumerations with
+            // fields can't be defined at all, but can still be pointed to as an opaque
+            // structure.
+            for (keyword, is_aggregate) in [
+                ("struct", info.structure_from_item(pointee).is_some()),
+                ("union", info.union_from_item(pointee).is_some()),
+                (
+                    "struct",
+                    info.enumeration_from_item(pointee)
+                        .is_some_and(has_variants_with_fields),
+                ),
+            ] {
+                if is_aggregate {
+                    let identifier =
+                        type_identifier(info, pointee, &format!("{context}_{name}"), c_identifier);
+                    self.forward.insert(format!("{keyword} {identifier}"));
+                    return Ok(format!("{keyword} {identifier} *{name}"));
+                }
+            }
+            return self.declare(pointee, &format!("*{name}"), path, context);
+        }
+        if let Some(array) = info.array_from_item(kind) {
+            let mut declarator = name.to_owned();
+            for (_, count) in array.dimensions() {
+                declarator.push_str(&format!("[{count}]"));
+            }
+            return self.declare(array.kind(), &declarator, path, context);
+        }
+        if info.subroutine_type_from_item(kind).is_some() {
+            return Ok(format!("void {name}(void)"));
+        }
+        if let Some(enumeration) = info.enumeration_from_item(kind) {
+            let identifier = self.define_enumeration(kind, enumeration, path)?;
+            return Ok(format!("{identifier} {name}"));
+        }
+        let fallback = format!("{context}_{}", c_identifier(name));
+        let declaration = self.define_aggregate(kind, path, &fallback)?;
+        Ok(format!("{declaration} {name}"))
+    }
+
+    /// Define a C-style enumeration as a set of constants, along with a typedef for an
+    /// integer of the same size as the discriminant, since a C `enum` is usually the
+    /// size of an `int`. Returns the name of the typedef.
+    fn define_enumeration(
+        &mut self,
+        item: DebugItem,
+        enumeration: &Enumeration,
+        path: &str,
+    ) -> Result<String, DebugTypeError> {
+        let identifier = type_identifier(self.target.info, item, "anonymous_enum", c_identifier);
+        if !self.defined.insert(item) {
+            return Ok(identifier);
+        }
+        let discriminant = self.target.discriminant_type(item, enumeration, path)?;
+        let Some(c_type) = c_base_type(discriminant) else {
+            return Err(self.target.unrepresentable(
+                item,
+                path,
+                "its discriminant isn't a plain integer",
+            ));
+        };
+        if enumeration.variants().iter().any(|variant| {
+            variant
+                .discriminant()
+                .is_some_and(|value| value < i64::MIN.into() || value > u64::MAX.into())
+        }) {
+            return Err(self.target.unrepresentable(
+                item,
+                path,
+                "a discriminant doesn't fit in 64 bits",
+            ));
+        }
+        let mut definition = format!("enum {identifier} {{\n");
+        for variant in enumeration.variants() {
+            let value = variant
+                .discriminant()
+                .map_or_else(String::new, |value| format!(" = {value}"));
+            definition.push_str(&format!(
+                "    {identifier}_{}{value},\n",
+                c_identifier(variant.name())
+            ));
+        }
+        definition.push_str(&format!("}};\ntypedef {c_type} {identifier};\n"));
+        self.definitions.push(definition);
+        Ok(identifier)
+    }
+
+    /// Define a structure or union, with explicit padding wherever the compiler left a
+    /// gap, and return how to refer to it, such as `struct foo`.
+    fn define_aggregate(
+        &mut self,
+        item: DebugItem,
+        path: &str,
+        fallback: &str,
+    ) -> Result<String, DebugTypeError> {
+        let info = self.target.info;
+        let identifier = type_identifier(info, item, fallback, c_identifier);
+        let (keyword, members, size, is_union) = aggregate(&self.target, item, path)?;
+        let declaration = format!("{keyword} {identifier}");
+        if !self.defined.insert(item) {
+            return Ok(declaration);
+        }
+
+        let planned = plan_fields(&self.target, members, size, is_union, path)?;
+        let mut body = String::new();
+        let mut padding_fields = 0;
+        for field in planned.fields {
+            match field {
+                Field::Member { name, kind } => {
+                    let member_path = member_path(path, &name);
+                    let declaration = self.declare(kind, &name, &member_path, &identifier)?;
+                    body.push_str(&format!("    {declaration};\n"));
+                }
+                Field::ZeroSized(name) => {
+                    body.push_str(&format!("    /* {name} is zero-sized */\n"));
+                }
+                Field::Padding(length) if is_union => {
+                    body.push_str(&format!("    uint8_t _size[{length}];\n"));
+                }
+                Field::Padding(length) => {
+                    body.push_str(&format!(
+                        "    uint8_t _padding{padding_fields}[{length}];\n"
+                    ));
+                    padding_fields += 1;
+                }
+            }
+        }
+
+        let attributes = if planned.packed {
+            " __attribute__((packed))"
+        } else {
+            ""
+        };
+        self.definitions.push(format!(
+            "{declaration} {{\n{body}}}{attributes};\n\
+             _Static_assert(sizeof({declaration}) == {size}, \"{declaration} has the wrong size\");\n"
+        ));
+        Ok(declaration)
+    }
+}
+
+/// Find the members of the structure or union `item`, rejecting structures that are
+/// really fat pointers. Returns the keyword that introduces it in C and Rust, its
+/// members, its size, and whether it is a union.
+fn aggregate<'a>(
+    target: &Target<'a>,
+    item: DebugItem,
+    path: &str,
+) -> Result<(&'static str, &'a [StructureMember], u64, bool), DebugTypeError> {
+    let info = target.info;
+    if let Some(union) = info.union_from_item(item) {
+        return Ok(("union", union.members(), union.size(), true));
+    }
+    let Some(structure) = info.structure_from_item(item) else {
+        return Err(target.unrepresentable(item, path, "it isn't a structure or union"));
+    };
+    // Slices, `&str` and trait objects are a pointer and a length or vtable, whose
+    // layout Rust doesn't promise.
+    let name = structure.name();
+    if name.starts_with('&') || name.starts_with('*') {
+        return Err(target.unrepresentable(
+            item,
+            path,
+            "it is a fat pointer, such as a slice or trait object",
+        ));
+    }
+    let Some(size) = structure.size() else {
+        return Err(target.unrepresentable(
+            item,
+            path,
+            "it is only declared, and no unit defines it",
+        ));
+    };
+    Ok(("struct", structure.members(), size, false))
+}
+
+/// The Rust type with the same size as `base_type`. Booleans and characters become plain
+/// integers, so that any bytes read from a dump are a valid value.
+fn rust_base_type(base_type: &BaseType) -> Option<&'static str> {
+    Some(match (base_type.encoding_kind(), base_type.size()) {
+        (BaseTypeEncoding::Float, 4) => "f32",
+        (BaseTypeEncoding::Float, 8) => "f64",
+        (BaseTypeEncoding::Signed | BaseTypeEncoding::SignedChar, size) => match size {
+            1 => "i8",
+            2 => "i16",
+            4 => "i32",
+            8 => "i64",
+            16 => "i128",
+            _ => return None,
+        },
+        (
+            BaseTypeEncoding::Unsigned
+            | BaseTypeEncoding::UnsignedChar
+            | BaseTypeEncoding::Address
+            | BaseTypeEncoding::Boolean
+            | BaseTypeEncoding::Utf,
+            size,
+        ) => match size {
+            1 => "u8",
+            2 => "u16",
+            4 => "u32",
+            8 => "u64",
+            16 => "u128",
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
+
+struct RustGenerator<'a> {
+    target: Target<'a>,
+    /// Complete definitions, each following the definitions it depends on.
+    definitions: Vec<String>,
+    /// Types that have a definition, so that each is only emitted once.
+    defined: HashSet<DebugItem>,
+}
+
+impl RustGenerator<'_> {
+    /// The Rust type to use for a member of type `kind`, defining any structures, unions
+    /// and enumerations that it contains along the way.
+    fn rust_type(
+        &mut self,
+        kind: DebugItem,
+        name: &str,
+        path: &str,
+        context: &str,
+    ) -> Result<String, DebugTypeError> {
+        let info = self.target.info;
+        let kind = info.resolve_item(kind);
+        if let Some(base_type) = info.base_type_from_item(kind) {
+            return rust_base_type(base_type).map(str::to_owned).ok_or_else(|| {
+                self.target.unrepresentable(
+                    kind,
+                    path,
+                    "there is no Rust type with the same size and encoding",
+                )
+            });
+        }
+        // Addresses on the target mean nothing on the host, so pointers are kept as
+        // integers of the target's pointer size.
+        if info.pointer_from_item(kind).is_some() {
+            return match info.byte_size(kind) {
+                Some(4) => Ok("u32".to_owned()),
+                Some(8) => Ok("u64".to_owned()),
+                _ => Err(self.target.unrepresentable(
+                    kind,
+                    path,
+                    "pointers of this size aren't supported",
+                )),
+            };
+        }
+        if let Some(array) = info.array_from_item(kind) {
+            let mut rust_type = self.rust_type(array.kind(), name, path, context)?;
+            for (_, count) in array.dimensions().iter().rev() {
+                rust_type = format!("[{rust_type}; {count}]");
+            }
+            return Ok(rust_type);
+        }
+        if let Some(enumeration) = info.enumeration_from_item(kind) {
+            return self.define_enumeration(kind, enumeration, path);
+        }
+        let fallback = format!("{context}{}", rust_type_identifier(name));
+        self.define_aggregate(kind, path, &fallback)
+    }
+
+    /// Define a C-style enumeration as a wrapper around its discriminant, with a
+    /// constant for each variant. A Rust `enum` can't hold values that aren't one of its
+    /// variants, which memory dumps are free to contain. Returns the name of the type.
+    fn define_enumeration(
+        &mut self,
+        item: DebugItem,
+        enumeration: &Enumeration,
+        path: &str,
+    ) -> Result<String, DebugTypeError> {
+        let identifier = type_identifier(
+            self.target.info,
+            item,
+            "AnonymousEnum",
+            rust_type_identifier,
+        );
+        if !self.defined.insert(item) {
+            return Ok(identifier);
+        }
+        let discriminant = self.target.discriminant_type(item, enumeration, path)?;
+        let Some(rust_type) = rust_base_type(discriminant) else {
+            return Err(self.target.unrepresentable(
+                item,
+                path,
+                "its discriminant isn't a plain integer",
+            ));
+        };
+        let mut constants = String::new();
+        for variant in enumeration.variants() {
+            if let Some(value) = variant.discriminant() {
+                constants.push_str(&format!(
+                    "    pub const {}: {identifier} = {identifier}({value});\n",
+                    rust_constant_identifier(variant.name())
+                ));
+            }
+        }
+        self.definitions.push(format!(
+            "#[repr(transparent)]\n\
+             #[derive(Clone, Copy, Debug, PartialEq, Eq)]\n\
+             pub struct {identifier}(pub {rust_type});\n\
+             \n\
+             impl {identifier} {{\n{constants}}}\n"
+        ));
+        Ok(identifier)
+    }
+
+    /// Define a structure or union, with explicit padding wherever the compiler left a
+    /// gap, and return its name.
+    fn define_aggregate(
+        &mut self,
+        item: DebugItem,
+        path: &str,
+        fallback: &str,
+    ) -> Result<String, DebugTypeError> {
+        let info = self.target.info;
+        let identifier = type_identifier(info, item, fallback, rust_type_identifier);
+        let (keyword, members, size, is_union) = aggregate(&self.target, item, path)?;
+        if !self.defined.insert(item) {
+            return Ok(identifier);
+        }
+
+        let planned = plan_fields(&self.target, members, size, is_union, path)?;
+        let mut body = String::new();
+        let mut padding_fields = 0;
+        for field in planned.fields {
+            match field {
+                Field::Member { name, kind } => {
+                    let member_path = member_path(path, &name);
+                    let rust_type = self.rust_type(kind, &name, &member_path, &identifier)?;
+                    body.push_str(&format!(
+                        "    pub {}: {rust_type},\n",
+                        rust_field_identifier(&name)
+                    ));
+                }
+                Field::ZeroSized(name) => {
+                    body.push_str(&format!("    // {name} is zero-sized\n"));
+                }
+                Field::Padding(length) if is_union => {
+                    body.push_str(&format!("    pub _size: [u8; {length}],\n"));
+                }
+                Field::Padding(length) => {
+                    body.push_str(&format!(
+                        "    pub _padding{padding_fields}: [u8; {length}],\n"
+                    ));
+                    padding_fields += 1;
+                }
+            }
+        }
+
+        let repr = if planned.packed { "C, packed" } else { "C" };
+        self.definitions.push(format!(
+            "#[repr({repr})]\n\
+             #[derive(Clone, Copy)]\n\
+             pub {keyword} {identifier} {{\n{body}}}\n\
+             \n\
+             const _: () = assert!(core::mem::size_of::<{identifier}>() == {size});\n"
+        ));
+        Ok(identifier)
+    }
+}
@@ -1,69 +1,136 @@
 use std::fmt::Debug;
 
-use gimli::{DebugInfoOffset, SectionId};
+use gimli::{DebugInfoOffset, Endianity, SectionId};
 
 use crate::{
     DebugInfo,
     memory::Read,
     unit_info::{self, MemoryLocation, StructOffset},
+    value::{Value, ValueOptions},
 };
 
 #[derive(Debug)]
 pub enum DebugTypeError {
     /// Multiple items matched the given path
-    MultipleMatches,
+    MultipleMatches {
+        path: String,
+        /// The names of all items that matched
+        candidates: Vec<String>,
+        location: Option<u64>,
+    },
     MemberNotFound {
         owner: String,
         member: String,
         /// A list of all available members
         available: Vec<String>,
         path: String,
+        location: Option<u64>,
     },
     GenericNotFound {
         owner: String,
         path: String,
+        location: Option<u64>,
     },
     StructureNotFound {
         owner: String,
         path: String,
+        location: Option<u64>,
     },
     BaseTypeNotFound {
         owner: String,
         path: String,
+        location: Option<u64>,
     },
     UnionNotFound {
         owner: String,
         path: String,
+        location: Option<u64>,
     },
     VariantNotFound {
         owner: String,
         variant: String,
         available: Vec<String>,
         path: String,
+        location: Option<u64>,
     },
     EnumerationNotFound {
         owner: String,
         path: String,
+        location: Option<u64>,
     },
     ArrayNotFound {
         value: String,
         path: String,
+        location: Option<u64>,
     },
     KindNotFound {
         owner: String,
         member: Option<String>,
         path: String,
+        location: Option<u64>,
     },
     KindIncorrect {
         owner: String,
         member: Option<String>,
-        attempted: String,
-        actual: String,
+        /// The kind of item that was asked for, such as `pointer`
+        attempted: Box<str>,
+        /// The kind of item that was found instead
+        actual: Box<str>,
         path: String,
+        location: Option<u64>,
     },
     NotRustSlice {
         owner: String,
         path: String,
+        location: Option<u64>,
+    },
+    /// None of the known layouts for a library type matched
+    UnrecognizedLayout {
+        owner: String,
+        attempted: Vec<String>,
+        path: String,
+        location: Option<u64>,
+    },
+    NotTraitObject {
+        owner: String,
+        path: String,
+        location: Option<u64>,
+    },
+    /// No vtable is known at the given address
+    VtableNotFound {
+        owner: String,
+        address: u64,
+        path: String,
+        location: Option<u64>,
+    },
+    IndexOutOfBounds {
+        owner: String,
+        index: usize,
+        length: usize,
+        path: String,
+        location: Option<u64>,
+    },
+    /// The pointer is a `void *`, so the type it points to is unknown
+    VoidPointer {
+        owner: String,
+        path: String,
+        location: Option<u64>,
+    },
+    /// The pointer is null, so there is nothing to follow
+    NullPointer {
+        owner: String,
+        path: String,
+        location: Option<u64>,
+    },
+    /// The type can't be expressed in the language a declaration is being generated for
+    Unrepresentable {
+        owner: String,
+        /// The name of the type that couldn't be expressed
+        kind: String,
+        language: &'static str,
+        reason: Box<str>,
+        path: String,
+        location: Option<u64>,
     },
     ReadError,
     SizeError(u64),
@@ -71,26 +138,55 @@ pub enum DebugTypeError {
     VariableNotFound(String),
 }
 
+impl DebugTypeError {
+    /// The address of the item that was being examined when the error occurred, if
+    /// it had been placed in memory.
+    pub fn location(&self) -> Option<u64> {
+        match self {
+            DebugTypeError::MultipleMatches { location, .. }
+            | DebugTypeError::MemberNotFound { location, .. }
+            | DebugTypeError::GenericNotFound { location, .. }
+            | DebugTypeError::StructureNotFound { location, .. }
+            | DebugTypeError::BaseTypeNotFound { location, .. }
+            | DebugTypeError::UnionNotFound { location, .. }
+            | DebugTypeError::VariantNotFound { location, .. }
+            | DebugTypeError::EnumerationNotFound { location, .. }
+            | DebugTypeError::ArrayNotFound { location, .. }
+            | DebugTypeError::KindNotFound { location, .. }
+            | DebugTypeError::KindIncorrect { location, .. }
+            | DebugTypeError::NotRustSlice { location, .. }
+            | DebugTypeError::UnrecognizedLayout { location, .. }
+            | DebugTypeError::NotTraitObject { location, .. }
+            | DebugTypeError::VtableNotFound { location, .. }
+            | DebugTypeError::IndexOutOfBounds { location, .. }
+            | DebugTypeError::VoidPointer { location, .. }
+            | DebugTypeError::NullPointer { location, .. }
+            | DebugTypeError::Unrepresentable { location, .. } => *location,
+            DebugTypeError::ReadError
+            | DebugTypeError::SizeError(_)
+            | DebugTypeError::LocationMissing
+            | DebugTypeError::VariableNotFound(_) => None,
+        }
+    }
+}
+
 impl core::fmt::Display for DebugTypeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            DebugTypeError::StructureNotFound { owner, path } => {
-                write!(
-                    f,
-                    "Structure for \"{owner}\" could not be found at path \"{path}\""
-                )
+            DebugTypeError::StructureNotFound { owner, path, .. } => {
+                write!(f, "Structure for \"{owner}\" could not be found")?;
+                write_path(f, path)
             }
-            DebugTypeError::EnumerationNotFound { owner, path } => {
-                write!(
-                    f,
-                    "Enumeration for \"{owner}\" could not be found at path \"{path}\""
-                )
+            DebugTypeError::EnumerationNotFound { owner, path, .. } => {
+                write!(f, "Enumeration for \"{owner}\" could not be found")?;
+                write_path(f, path)
             }
             DebugTypeError::VariantNotFound {
                 owner,
                 variant,
                 available,
                 path,
+                ..
             } => {
                 if available.is_empty() {
                     write!(
@@ -105,16 +201,15 @@ impl core::fmt::Display for DebugTypeError {
                     )
                 }
             }
-            DebugTypeError::UnionNotFound { owner, path } => {
-                write!(f, "Union \"{owner}\" could not be found at path \"{path}\"")
+            DebugTypeError::UnionNotFound { owner, path, .. } => {
+                write!(f, "Union \"{owner}\" could not be found")?;
+                write_path(f, path)
             }
-            DebugTypeError::BaseTypeNotFound { owner, path } => {
-                write!(
-                    f,
-                    "Base type \"{owner}\" could not be found at path \"{path}\""
-                )
+            DebugTypeError::BaseTypeNotFound { owner, path, .. } => {
+                write!(f, "Base type \"{owner}\" could not be found")?;
+                write_path(f, path)
             }
-            DebugTypeError::ArrayNotFound { value, path } => {
+            DebugTypeError::ArrayNotFound { value, path, .. } => {
                 write!(
                     f,
                     "Array could not be found for item \"{value}\" at path \"{path}\""
@@ -125,6 +220,7 @@ impl core::fmt::Display for DebugTypeError {
                 member,
                 available,
                 path,
+                ..
             } => {
                 if available.is_empty() {
                     write!(
@@ -139,7 +235,7 @@ impl core::fmt::Display for DebugTypeError {
                     )
                 }
             }
-            DebugTypeError::GenericNotFound { owner, path } => {
+            DebugTypeError::GenericNotFound { owner, path, .. } => {
                 write!(
                     f,
                     "Generic not found in struct \"{owner}\" at path \"{path}\""
@@ -153,6 +249,7 @@ impl core::fmt::Display for DebugTypeError {
                 owner,
                 member,
                 path,
+                ..
             } => {
                 if let Some(member) = member {
                     write!(
@@ -172,6 +269,7 @@ impl core::fmt::Display for DebugTypeError {
                 attempted,
                 actual,
                 path,
+                ..
             } => {
                 if let Some(member) = member {
                     write!(
@@ -185,22 +283,377 @@ impl core::fmt::Display for DebugTypeError {
                     )
                 }
             }
-            DebugTypeError::NotRustSlice { owner, path } => {
+            DebugTypeError::NotRustSlice { owner, path, .. } => {
                 write!(f, "Type \"{owner}\" is not a Rust slice at path \"{path}\"")
             }
+            DebugTypeError::UnrecognizedLayout {
+                owner,
+                attempted,
+                path,
+                ..
+            } => {
+                write!(
+                    f,
+                    "Type \"{owner}\" has an unrecognized layout at path \"{path}\" (tried {})",
+                    attempted.join(", ")
+                )
+            }
+            DebugTypeError::NotTraitObject { owner, path, .. } => {
+                write!(
+                    f,
+                    "Type \"{owner}\" is not a trait object at path \"{path}\""
+                )
+            }
+            DebugTypeError::VtableNotFound {
+                owner,
+                address,
+                path,
+                ..
+            } => {
+                write!(
+                    f,
+                    "No vtable for \"{owner}\" is known at 0x{address:x} at path \"{path}\""
+                )
+            }
+            DebugTypeError::IndexOutOfBounds {
+                owner,
+                index,
+                length,
+                path,
+                ..
+            } => {
+                write!(
+                    f,
+                    "Index {index} is out of bounds for \"{owner}\" of length {length} at path \"{path}\""
+                )
+            }
+            DebugTypeError::NullPointer { owner, path, .. } => {
+                write!(f, "Pointer in \"{owner}\" at path \"{path}\" is null")
+            }
+            DebugTypeError::VoidPointer { owner, path, .. } => {
+                write!(
+                    f,
+                    "Pointer in \"{owner}\" at path \"{path}\" is a void pointer with no known type"
+                )
+            }
+            DebugTypeError::Unrepresentable {
+                owner,
+                kind,
+                language,
+                reason,
+                path,
+                ..
+            } => {
+                if path.is_empty() {
+                    write!(
+                        f,
+                        "Type \"{kind}\" can't be represented in {language} because {reason}"
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Member \"{path}\" of \"{owner}\" has type \"{kind}\", which can't be represented in {language} because {reason}"
+                    )
+                }
+            }
             DebugTypeError::ReadError => {
                 write!(f, "An error occurred when reading memory from the target")
             }
             DebugTypeError::LocationMissing => write!(f, "There was no location data available"),
-            DebugTypeError::MultipleMatches => {
-                write!(f, "Multiple items matched the specified string")
+            DebugTypeError::MultipleMatches {
+                path, candidates, ..
+            } => {
+                write!(
+                    f,
+                    "Multiple items matched \"{path}\": {}",
+                    candidates.join(", ")
+                )
             }
+        }?;
+        if let Some(address) = self.location() {
+            write!(f, " (at {address:#x})")?;
         }
+        Ok(())
     }
 }
 
 impl core::error::Error for DebugTypeError {}
 
+/// Write ` at path "..."`, unless the item was looked up directly rather than reached
+/// through a path.
+fn write_path(f: &mut std::fmt::Formatter<'_>, path: &str) -> std::fmt::Result {
+    if path.is_empty() {
+        Ok(())
+    } else {
+        write!(f, " at path \"{path}\"")
+    }
+}
+
+/// Read an unsigned value of `size` bytes from the target and decode it using the
+/// specified byte order. Values are always read with the big-endian methods of the
+/// `Read` trait and swapped for little-endian targets, since those are the methods
+/// whose meaning [`EndiannessAwareReader`] leaves alone. Reading through one therefore
+/// doesn't swap the bytes a second time.
+///
+/// [`EndiannessAwareReader`]: crate::memory::EndiannessAwareReader
+pub(crate) fn read_unsigned<S: Read + ?Sized>(
+    memory_source: &mut S,
+    address: u64,
+    size: u64,
+    endian: gimli::RunTimeEndian,
+) -> Result<u64, DebugTypeError> {
+    let little_endian = !endian.is_big_endian();
+    Ok(match size {
+        1 => memory_source
+            .read_u8(address)
+            .map_err(|_| DebugTypeError::ReadError)?
+            .into(),
+        2 => {
+            let value = memory_source
+                .read_u16_be(address)
+                .map_err(|_| DebugTypeError::ReadError)?;
+            u64::from(if little_endian {
+                value.swap_bytes()
+            } else {
+                value
+            })
+        }
+        4 => {
+            let value = memory_source
+                .read_u32_be(address)
+                .map_err(|_| DebugTypeError::ReadError)?;
+            u64::from(if little_endian {
+                value.swap_bytes()
+            } else {
+                value
+            })
+        }
+        8 => {
+            let value = memory_source
+                .read_u64_be(address)
+                .map_err(|_| DebugTypeError::ReadError)?;
+            if little_endian {
+                value.swap_bytes()
+            } else {
+                value
+            }
+        }
+        size => return Err(DebugTypeError::SizeError(size)),
+    })
+}
+
+/// Read a 128-bit value from the target, taking into account the byte order of the target.
+/// Like [`read_unsigned`], this is built from big-endian reads.
+pub(crate) fn read_u128<S: Read + ?Sized>(
+    memory_source: &mut S,
+    address: u64,
+    endian: gimli::RunTimeEndian,
+) -> Result<u128, DebugTypeError> {
+    let mut read = |address| {
+        memory_source
+            .read_u64_be(address)
+            .map(u128::from)
+            .map_err(|_| DebugTypeError::ReadError)
+    };
+    let first = read(address)?;
+    let second = read(address + 8)?;
+    let value = first << 64 | second;
+    Ok(if endian.is_big_endian() {
+        value
+    } else {
+        value.swap_bytes()
+    })
+}
+
+/// Read a member that is a base type using one of the `DebugBaseType::as_*` readers.
+/// A member wider than `T` is reported as a [`DebugTypeError::SizeError`].
+fn read_member<S: Read + ?Sized, T>(
+    member: DebugStructureMember<'_>,
+    memory_source: &mut S,
+    read: fn(&DebugBaseType, &mut S) -> Option<T>,
+) -> Result<T, DebugTypeError> {
+    let base_type = member.base_type()?;
+    base_type.location()?;
+    if base_type.size() > core::mem::size_of::<T>() as u64 {
+        return Err(DebugTypeError::SizeError(base_type.size()));
+    }
+    read(&base_type, memory_source).ok_or(DebugTypeError::ReadError)
+}
+
+/// Generate `read_field_*` methods for a type that has a `member_named()` method.
+macro_rules! field_readers {
+    () => {
+        field_readers! {
+            @ read_field_u8 => as_u8: u8,
+            read_field_u16 => as_u16: u16,
+            read_field_u32 => as_u32: u32,
+            read_field_u64 => as_u64: u64,
+            read_field_i8 => as_i8: i8,
+            read_field_i16 => as_i16: i16,
+            read_field_i32 => as_i32: i32,
+            read_field_i64 => as_i64: i64,
+        }
+    };
+    (@ $($method:ident => $reader:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Read the member `name` as a `", stringify!($ty), "`.")]
+            pub fn $method<S: Read + ?Sized>(
+                &self,
+                name: &str,
+                memory_source: &mut S,
+            ) -> Result<$ty, DebugTypeError> {
+                read_member(self.member_named(name)?, memory_source, DebugBaseType::$reader)
+            }
+        )*
+    };
+}
+
+/// The names of `members` for use in error messages. Members without a name are
+/// listed by their position.
+fn member_names(members: &[unit_info::StructureMember]) -> Vec<String> {
+    members
+        .iter()
+        .enumerate()
+        .map(|(index, member)| {
+            member
+                .name()
+                .map(|n| n.to_owned())
+                .unwrap_or_else(|| format!("anonymous[{index}]"))
+        })
+        .collect()
+}
+
+/// The address of element `index` of an array or slice that starts at `location`, or
+/// `None` if it would lie beyond the end of the address space.
+fn element_location(
+    location: MemoryLocation,
+    stride: StructOffset,
+    index: u64,
+) -> Option<MemoryLocation> {
+    stride
+        .checked_mul(StructOffset::new(index))
+        .and_then(|offset| location.checked_add(offset))
+}
+
+/// The longest `&str` that [`DebugSlice::read_as_str()`] will read. The length of a
+/// slice comes from target memory, so a corrupt or uninitialized one could otherwise
+/// ask for gigabytes.
+pub const MAX_STR_LENGTH: u64 = 1024 * 1024;
+
+/// Read `size` bytes starting at `location` in a single burst.
+fn read_bytes<S: Read + ?Sized>(
+    memory_source: &mut S,
+    location: Option<unit_info::MemoryLocation>,
+    size: u64,
+) -> Result<Vec<u8>, DebugTypeError> {
+    let address = location.ok_or(DebugTypeError::LocationMissing)?.0;
+    if size == 0 {
+        return Err(DebugTypeError::SizeError(size));
+    }
+    let length = usize::try_from(size).map_err(|_| DebugTypeError::SizeError(size))?;
+    let mut data = vec![0u8; length];
+    memory_source
+        .begin()
+        .map_err(|_| DebugTypeError::ReadError)?;
+    let result = memory_source.read(&mut data, address);
+    memory_source.finish();
+    result.map_err(|_| DebugTypeError::ReadError)?;
+    Ok(data)
+}
+
+/// A NUL-terminated string read from the target, as returned by
+/// [`DebugPointer::read_cstring()`] and [`DebugArray::as_cstring()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CStringValue {
+    bytes: Vec<u8>,
+    terminated: bool,
+}
+
+impl CStringValue {
+    /// The bytes of the string, not including the terminator.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Whether a NUL terminator was found. If not, the string was cut off at the
+    /// length limit or at the end of its buffer.
+    pub fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+
+    /// Decode the string as UTF-8, replacing invalid sequences with `U+FFFD`.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(&self.bytes).into_owned()
+    }
+}
+
+impl core::fmt::Display for CStringValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.bytes))
+    }
+}
+
+/// Read a NUL-terminated string of at most `max_length` bytes starting at `address`.
+/// The string is read in chunks, and a chunk that can't be read in one go is read a
+/// byte at a time in case the string ends just before unreadable memory.
+fn read_cstring<S: Read + ?Sized>(
+    memory_source: &mut S,
+    address: u64,
+    max_length: usize,
+) -> Result<CStringValue, DebugTypeError> {
+    const CHUNK_SIZE: usize = 64;
+    let mut bytes = Vec::new();
+    memory_source
+        .begin()
+        .map_err(|_| DebugTypeError::ReadError)?;
+    let result = (|| {
+        while bytes.len() < max_length {
+            let mut chunk = [0u8; CHUNK_SIZE];
+            let chunk = &mut chunk[..CHUNK_SIZE.min(max_length - bytes.len())];
+            let chunk_address = address + bytes.len() as u64;
+            if memory_source.read(chunk, chunk_address).is_err() {
+                for (offset, byte) in chunk.iter_mut().enumerate() {
+                    *byte = memory_source
+                        .read_u8(chunk_address + offset as u64)
+                        .map_err(|_| DebugTypeError::ReadError)?;
+                    if *byte == 0 {
+                        break;
+                    }
+                }
+            }
+            if let Some(end) = chunk.iter().position(|byte| *byte == 0) {
+                bytes.extend_from_slice(&chunk[..end]);
+                return Ok(true);
+            }
+            bytes.extend_from_slice(chunk);
+        }
+        Ok(false)
+    })();
+    memory_source.finish();
+    Ok(CStringValue {
+        terminated: result?,
+        bytes,
+    })
+}
+
+/// Turn a flat element index into a path component such as `[1][2]`, with one
+/// index per dimension of the array.
+pub(crate) fn array_index_name(array: &unit_info::Array, mut index: usize) -> String {
+    let mut indices = vec![];
+    for (_, count) in array.dimensions().iter().rev() {
+        if *count == 0 {
+            break;
+        }
+        indices.push(index % count);
+        index /= count;
+    }
+    indices
+        .iter()
+        .rev()
+        .map(|index| format!("[{index}]"))
+        .collect()
+}
+
 /// Append this name to the parent string, if it's not empty
 pub fn make_path_name(parent: &str, this: &str) -> String {
     if parent.is_empty() {
@@ -209,6 +662,194 @@ pub fn make_path_name(parent: &str, this: &str) -> String {
     format!("{parent}.{this}")
 }
 
+/// Split one segment of a member path, such as `buf[3][1]`, into the member name and
+/// the array indices that follow it. Returns `None` if the indices are malformed.
+pub(crate) fn split_path_segment(segment: &str) -> Option<(&str, Vec<usize>)> {
+    let Some(open) = segment.find('[') else {
+        return Some((segment, vec![]));
+    };
+    let (name, mut rest) = segment.split_at(open);
+    let mut indices = vec![];
+    while let Some(inner) = rest.strip_prefix('[') {
+        let (index, remainder) = inner.split_once(']')?;
+        indices.push(index.trim().parse().ok()?);
+        rest = remainder;
+    }
+    rest.is_empty().then_some((name, indices))
+}
+
+/// A type that has named members that a member path can descend into.
+pub(crate) enum MemberContainer<'a> {
+    Structure(DebugStructure<'a>),
+    Union(DebugUnion<'a>),
+}
+
+impl<'a> MemberContainer<'a> {
+    fn member_named(&self, name: &str) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        match self {
+            MemberContainer::Structure(structure) => structure.member_named(name),
+            MemberContainer::Union(union) => union.member_named(name),
+        }
+    }
+
+    /// Walk a dotted member path such as `a.buf[3].x`, starting from this container.
+    fn member_path(self, path: &str) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        match self.walk(path)? {
+            PathEnd::Member(member) => Ok(member),
+            PathEnd::Element {
+                owner,
+                segment,
+                item,
+            } => Err(DebugTypeError::KindIncorrect {
+                owner,
+                member: Some(segment),
+                attempted: "structure member".into(),
+                actual: "array element".into(),
+                path: item.path,
+                location: item.location.map(|location| location.0),
+            }),
+        }
+    }
+
+    /// The byte offset of the item at the end of `path` from the start of this container.
+    /// The container must have been created at address 0.
+    pub(crate) fn offset_of(self, path: &str) -> Result<u64, DebugTypeError> {
+        let (location, _) = self.walk(path)?.resolved();
+        location
+            .map(|location| location.0)
+            .ok_or(DebugTypeError::LocationMissing)
+    }
+
+    /// Walk a dotted member path, which may end with an array index.
+    fn walk(self, path: &str) -> Result<PathEnd<'a>, DebugTypeError> {
+        let mut container = self;
+        let mut segments = path.split('.').peekable();
+        while let Some(segment) = segments.next() {
+            let (name, indices) = split_path_segment(segment).unwrap_or((segment, vec![]));
+            let member = container.member_named(name)?;
+            let is_last = segments.peek().is_none();
+            if indices.is_empty() {
+                if is_last {
+                    return Ok(PathEnd::Member(member));
+                }
+                container = member.member_container()?;
+                continue;
+            }
+
+            let item = member.array()?.get_indexed(&indices)?;
+            if is_last {
+                return Ok(PathEnd::Element {
+                    owner: member.parent_name.clone(),
+                    segment: segment.to_owned(),
+                    item,
+                });
+            }
+            container = item.member_container()?;
+        }
+        unreachable!("str::split always yields at least one segment")
+    }
+}
+
+/// The item at the end of a member path.
+enum PathEnd<'a> {
+    Member(DebugStructureMember<'a>),
+    /// An element of an array member, reached through the path segment `segment`
+    /// of the structure or union `owner`.
+    Element {
+        owner: String,
+        segment: String,
+        item: DebugArrayItem<'a>,
+    },
+}
+
+impl PathEnd<'_> {
+    /// The location and type of the item.
+    fn resolved(&self) -> (Option<MemoryLocation>, unit_info::DebugItem) {
+        match self {
+            PathEnd::Member(member) => member.resolved(),
+            PathEnd::Element { item, .. } => (item.location, item.kind),
+        }
+    }
+}
+
+/// Build the error for an item of type `kind` that was used as the wrong kind of type,
+/// describing what the item actually is.
+fn kind_mismatch(
+    info: &DebugInfo,
+    kind_index: unit_info::DebugItem,
+    owner: &str,
+    member: Option<&str>,
+    attempted: &str,
+    path: &str,
+    location: Option<unit_info::MemoryLocation>,
+) -> DebugTypeError {
+    let location = location.map(|location| location.0);
+    let member = member.map(|s| s.to_owned());
+    let attempted: Box<str> = attempted.into();
+    if info.structure_from_item(kind_index).is_some() {
+        DebugTypeError::KindIncorrect {
+            owner: owner.to_owned(),
+            member,
+            attempted,
+            actual: "structure".into(),
+            path: path.to_owned(),
+            location,
+        }
+    } else if info.enumeration_from_item(kind_index).is_some() {
+        DebugTypeError::KindIncorrect {
+            owner: owner.to_owned(),
+            member,
+            attempted,
+            actual: "enumeration".into(),
+            path: path.to_owned(),
+            location,
+        }
+    } else if info.pointer_from_item(kind_index).is_some() {
+        DebugTypeError::KindIncorrect {
+            owner: owner.to_owned(),
+            member,
+            attempted,
+            actual: "pointer".into(),
+            path: path.to_owned(),
+            location,
+        }
+    } else if info.array_from_item(kind_index).is_some() {
+        DebugTypeError::KindIncorrect {
+            owner: owner.to_owned(),
+            member,
+            attempted,
+            actual: "array".into(),
+            path: path.to_owned(),
+            location,
+        }
+    } else if info.union_from_item(kind_index).is_some() {
+        DebugTypeError::KindIncorrect {
+            owner: owner.to_owned(),
+            member,
+            attempted,
+            actual: "union".into(),
+            path: path.to_owned(),
+            location,
+        }
+    } else if info.base_type_from_item(kind_index).is_some() {
+        DebugTypeError::KindIncorrect {
+            owner: owner.to_owned(),
+            member,
+            attempted,
+            actual: "base type".into(),
+            path: path.to_owned(),
+            location,
+        }
+    } else {
+        DebugTypeError::KindNotFound {
+            owner: owner.to_owned(),
+            member,
+            path: path.to_owned(),
+            location,
+        }
+    }
+}
+
 pub struct DebugArrayItem<'a> {
     unit: &'a unit_info::UnitInfo,
     info: &'a DebugInfo,
@@ -230,9 +871,23 @@ impl core::fmt::Debug for DebugArrayItem<'_> {
     }
 }
 
-/// An item stored within an array. The item may be a Structure, an Enumeration,
-/// or a BaseType of u8.
+/// An item stored within an array. The item may be any type, including another array.
 impl<'a> DebugArrayItem<'a> {
+    /// Build the error for an item that was used as the wrong kind of type, naming the
+    /// kind that it actually is.
+    fn find_alternatives(&self, attempted: &str) -> DebugTypeError {
+        let element = self.path.rsplit('.').next();
+        kind_mismatch(
+            self.info,
+            self.kind,
+            &self.parent_name,
+            element,
+            attempted,
+            &self.path,
+            self.location,
+        )
+    }
+
     /// If the Array is an array of structs, return the underlying Structure object.
     pub fn structure(&self) -> Result<DebugStructure<'a>, DebugTypeError> {
         self.info
@@ -247,10 +902,7 @@ impl<'a> DebugArrayItem<'a> {
                 // as a struct and we already have the path defined.
                 path: self.path.clone(),
             })
-            .ok_or(DebugTypeError::StructureNotFound {
-                owner: self.parent_name.clone(),
-                path: self.path.clone(),
-            })
+            .ok_or_else(|| self.find_alternatives("structure"))
     }
 
     /// If the Array is an array of enums, return the underlying Enumeration object.
@@ -265,20 +917,72 @@ impl<'a> DebugArrayItem<'a> {
                 enumeration,
                 path: self.path.clone(),
             })
-            .ok_or_else(|| DebugTypeError::EnumerationNotFound {
+            .ok_or_else(|| self.find_alternatives("enumeration"))
+    }
+
+    /// If the Array is an array of pointers, return the pointer stored in this element.
+    pub fn pointer(&self) -> Result<DebugPointer<'a>, DebugTypeError> {
+        self.info
+            .pointer_from_item(self.kind)
+            .map(|pointer| DebugPointer {
+                unit: self.unit,
+                info: self.info,
+                location: self.location,
+                offset: self.offset,
+                pointer,
+                parent_name: self.parent_name.clone(),
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| self.find_alternatives("pointer"))
+    }
+
+    /// If the Array is an array of unions, return the underlying Union object.
+    pub fn union(&self) -> Result<DebugUnion<'a>, DebugTypeError> {
+        self.info
+            .union_from_item(self.kind)
+            .map(|union| DebugUnion {
+                unit: self.unit,
+                info: self.info,
+                location: self.location,
+                offset: self.offset,
+                union,
                 path: self.path.clone(),
-                owner: self.parent_name.clone(),
             })
+            .ok_or_else(|| self.find_alternatives("union"))
+    }
+
+    /// If the Array is an array of arrays, return the inner array.
+    pub fn array(&self) -> Result<DebugArray<'a>, DebugTypeError> {
+        self.info
+            .array_from_item(self.kind)
+            .map(|array| DebugArray {
+                unit: self.unit,
+                info: self.info,
+                location: self.location,
+                offset: self.offset,
+                array,
+                parent_name: self.parent_name.clone(),
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| self.find_alternatives("array"))
     }
 
     pub fn base_type(&self) -> Result<DebugBaseType, DebugTypeError> {
         self.info
             .base_type_from_item(self.kind)
-            .map(|base_type| DebugBaseType::from_base_type(self.location, base_type))
-            .ok_or_else(|| DebugTypeError::BaseTypeNotFound {
-                path: self.path.clone(),
-                owner: self.parent_name.clone(),
+            .map(|base_type| {
+                DebugBaseType::from_base_type(self.location, base_type, self.info.endian())
             })
+            .ok_or_else(|| self.find_alternatives("base type"))
+    }
+
+    /// The type of this item.
+    pub(crate) fn kind(&self) -> unit_info::DebugItem {
+        self.kind
+    }
+
+    pub(crate) fn location(&self) -> Option<unit_info::MemoryLocation> {
+        self.location
     }
 
     /// Treat the Array as a `u8`. This can be useful for reading strings, which are
@@ -292,22 +996,23 @@ impl<'a> DebugArrayItem<'a> {
         }
         None
     }
+
+    /// Treat this item as a structure or a union so that its members can be searched.
+    fn member_container(&self) -> Result<MemberContainer<'a>, DebugTypeError> {
+        if let Ok(union) = self.union() {
+            return Ok(MemberContainer::Union(union));
+        }
+        self.structure().map(MemberContainer::Structure)
+    }
 }
 
-/// An iterator over array items.
+/// An iterator over array items. Multi-dimensional arrays are flattened in
+/// row-major order.
 pub struct DebugArrayIterator<'a> {
-    unit: &'a unit_info::UnitInfo,
-    info: &'a DebugInfo,
-    location: Option<unit_info::MemoryLocation>,
-    offset: unit_info::StructOffset,
-    array: &'a unit_info::Array,
+    array: DebugArray<'a>,
     index: usize,
     count: usize,
-    element_size: StructOffset,
-    /// The name of the item that encloses this object
-    parent_name: String,
-    /// The full path we took to get here
-    path: String,
+    stride: StructOffset,
 }
 
 impl<'a> Iterator for DebugArrayIterator<'a> {
@@ -317,24 +1022,15 @@ impl<'a> Iterator for DebugArrayIterator<'a> {
         if self.index >= self.count {
             return None;
         }
-        let location = self
-            .location
-            .map(|loc| loc + self.element_size * StructOffset::new(self.index as u64));
+        let item = self.array.item_at(self.index, self.stride);
         self.index += 1;
-        Some(DebugArrayItem {
-            unit: self.unit,
-            info: self.info,
-            location,
-            offset: self.offset,
-            kind: self.array.kind(),
-            parent_name: self.parent_name.clone(),
-            path: make_path_name(&self.path, &format!("[{}]", self.index)),
-        })
+        Some(item)
     }
 }
 
 /// An array of values in memory. The size of the array is taken from the Dwarf data and
 /// is fixed at compile time.
+#[derive(Clone)]
 pub struct DebugArray<'a> {
     unit: &'a unit_info::UnitInfo,
     info: &'a DebugInfo,
@@ -373,26 +1069,102 @@ impl<'a> DebugArray<'a> {
             })
     }
 
-    pub fn iter(&self) -> Result<DebugArrayIterator<'a>, DebugTypeError> {
-        let element_size = self.info.size_from_item(self.array.kind()).ok_or_else(|| {
-            DebugTypeError::KindNotFound {
+    /// The size of a single element of this array, in bytes.
+    pub fn element_size(&self) -> Result<StructOffset, DebugTypeError> {
+        self.info
+            .byte_size(self.array.kind())
+            .map(StructOffset::new)
+            .ok_or_else(|| DebugTypeError::KindNotFound {
                 owner: self.parent_name.clone(),
                 path: self.path.clone(),
                 member: None,
-            }
-        })?;
-        let count = self.count();
-        Ok(DebugArrayIterator {
+                location: self.location.map(|location| location.0),
+            })
+    }
+
+    /// The distance in bytes from the start of one element to the start of the next.
+    /// This is larger than the element size when elements are padded.
+    pub fn stride(&self) -> Result<StructOffset, DebugTypeError> {
+        match self.array.byte_stride() {
+            Some(stride) => Ok(StructOffset::new(stride)),
+            None => self.element_size(),
+        }
+    }
+
+    /// Return the element at `index` without walking the elements that come before it.
+    pub fn get(&self, index: usize) -> Result<DebugArrayItem<'a>, DebugTypeError> {
+        let length = self.count();
+        if index >= length {
+            return Err(DebugTypeError::IndexOutOfBounds {
+                owner: self.parent_name.clone(),
+                index,
+                length,
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        }
+        Ok(self.item_at(index, self.stride()?))
+    }
+
+    /// Build the item at `index`, which must already have been checked against the
+    /// length of the array.
+    fn item_at(&self, index: usize, stride: StructOffset) -> DebugArrayItem<'a> {
+        DebugArrayItem {
             unit: self.unit,
             info: self.info,
-            location: self.location,
+            location: self
+                .location
+                .and_then(|location| element_location(location, stride, index as u64)),
             offset: self.offset,
-            array: self.array,
-            index: 0,
-            count,
-            element_size,
+            kind: self.array.kind(),
             parent_name: self.parent_name.clone(),
-            path: self.path.clone(),
+            path: make_path_name(&self.path, &array_index_name(self.array, index)),
+        }
+    }
+
+    /// Return the element at the given index, with one index for each dimension of
+    /// the array. Any further indices are applied to the element, which allows arrays
+    /// of arrays such as Rust's `[[u8; 3]; 2]` to be indexed as `[1][2]`.
+    pub fn get_indexed(&self, indices: &[usize]) -> Result<DebugArrayItem<'a>, DebugTypeError> {
+        let dimensions = self.array.dimensions();
+        if indices.len() < dimensions.len() {
+            return Err(DebugTypeError::KindIncorrect {
+                owner: self.parent_name.clone(),
+                member: None,
+                attempted: format!("{}-dimensional array", indices.len()).into(),
+                actual: format!("{}-dimensional array", dimensions.len()).into(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        }
+        let (indices, remaining) = indices.split_at(dimensions.len());
+        let mut flat = 0;
+        for (&index, &(_, length)) in indices.iter().zip(dimensions) {
+            if index >= length {
+                return Err(DebugTypeError::IndexOutOfBounds {
+                    owner: self.parent_name.clone(),
+                    index,
+                    length,
+                    path: self.path.clone(),
+                    location: self.location.map(|location| location.0),
+                });
+            }
+            flat = flat * length + index;
+        }
+        let item = self.get(flat)?;
+        if remaining.is_empty() {
+            return Ok(item);
+        }
+        item.array()?.get_indexed(remaining)
+    }
+
+    pub fn iter(&self) -> Result<DebugArrayIterator<'a>, DebugTypeError> {
+        let stride = self.stride()?;
+        Ok(DebugArrayIterator {
+            array: self.clone(),
+            index: 0,
+            count: self.count(),
+            stride,
         })
     }
 
@@ -408,6 +1180,90 @@ impl<'a> DebugArray<'a> {
         self.offset = unit_info::StructOffset::new(0);
         self
     }
+
+    /// Place this array at a different address, keeping the same element type and length.
+    pub fn with_address(mut self, address: u64) -> Self {
+        self.location = Some(unit_info::MemoryLocation(address));
+        self
+    }
+
+    /// Check that the elements of this array are base types that are `size` bytes wide
+    /// and packed together, so that the whole array can be read in one go.
+    fn check_packed_base_type(&self, size: u64) -> Result<(), DebugTypeError> {
+        let kind = self.array.kind();
+        let Some(base_type) = self.info.base_type_from_item(kind) else {
+            return Err(kind_mismatch(
+                self.info,
+                kind,
+                &self.parent_name,
+                None,
+                "base type",
+                &self.path,
+                self.location,
+            ));
+        };
+        if base_type.size() != size {
+            return Err(DebugTypeError::SizeError(base_type.size()));
+        }
+        let stride = self.stride()?;
+        if stride.0 != size {
+            return Err(DebugTypeError::SizeError(stride.0));
+        }
+        Ok(())
+    }
+
+    /// Read a `[u8; N]` in a single burst. Arrays of anything other than a 1-byte base
+    /// type are rejected, with [`DebugTypeError::KindIncorrect`] or
+    /// [`DebugTypeError::SizeError`].
+    pub fn read_bytes<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<Vec<u8>, DebugTypeError> {
+        self.check_packed_base_type(1)?;
+        read_bytes(memory_source, self.location, self.count() as u64)
+    }
+
+    /// Read a `[u32; N]` in a single burst, decoding each word in the target's byte order.
+    pub fn read_u32_array<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<Vec<u32>, DebugTypeError> {
+        self.check_packed_base_type(4)?;
+        let big_endian = self.info.endian().is_big_endian();
+        Ok(
+            read_bytes(memory_source, self.location, 4 * self.count() as u64)?
+                .chunks_exact(4)
+                .map(|word| {
+                    let word = [word[0], word[1], word[2], word[3]];
+                    if big_endian {
+                        u32::from_be_bytes(word)
+                    } else {
+                        u32::from_le_bytes(word)
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Read a `char name[32]`-style buffer as a string, stopping at the first NUL.
+    pub fn as_cstring<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<CStringValue, DebugTypeError> {
+        let element_size = self.element_size()?;
+        if element_size.0 != 1 {
+            return Err(DebugTypeError::SizeError(element_size.0));
+        }
+        let mut bytes = read_bytes(memory_source, self.location, self.count() as u64)?;
+        let end = bytes.iter().position(|byte| *byte == 0);
+        if let Some(end) = end {
+            bytes.truncate(end);
+        }
+        Ok(CStringValue {
+            bytes,
+            terminated: end.is_some(),
+        })
+    }
 }
 
 impl core::ops::Deref for DebugArray<'_> {
@@ -428,24 +1284,71 @@ impl core::fmt::Debug for DebugArray<'_> {
     }
 }
 
+/// A value read from a base type, as returned by [`DebugBaseType::value()`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BaseValue {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    /// The raw bytes of a value whose encoding or size isn't understood, in the order
+    /// they appear in memory.
+    Bytes(Vec<u8>),
+}
+
+impl core::fmt::Display for BaseValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaseValue::Unsigned(value) => write!(f, "{value}"),
+            BaseValue::Signed(value) => write!(f, "{value}"),
+            BaseValue::Float(value) => write!(f, "{value}"),
+            BaseValue::Bool(value) => write!(f, "{value}"),
+            BaseValue::Char(value) => write!(f, "{value:?}"),
+            BaseValue::Bytes(bytes) => {
+                for (index, byte) in bytes.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 pub struct DebugBaseType {
     location: Option<unit_info::MemoryLocation>,
     size: u64,
     name: String,
+    endian: gimli::RunTimeEndian,
+    encoding: Option<gimli::DwAte>,
 }
 
 impl DebugBaseType {
+    /// Create a new base type. Values are assumed to be little-endian; use
+    /// [`DebugBaseType::with_endian()`] for big-endian targets.
     pub fn new(location: Option<unit_info::MemoryLocation>, name: String, size: u64) -> Self {
         Self {
             location,
             size,
             name,
+            endian: gimli::RunTimeEndian::Little,
+            encoding: None,
         }
     }
 
+    /// Set the byte order used when reading this value from the target.
+    pub fn with_endian(mut self, endian: gimli::RunTimeEndian) -> Self {
+        self.endian = endian;
+        self
+    }
+
     pub(crate) fn from_base_type(
         location: Option<unit_info::MemoryLocation>,
         base_type: &unit_info::BaseType,
+        endian: gimli::RunTimeEndian,
     ) -> Self {
         let size = base_type.size();
         let name = base_type.name().to_owned();
@@ -453,6 +1356,8 @@ impl DebugBaseType {
             location,
             size,
             name,
+            endian,
+            encoding: Some(base_type.encoding()),
         }
     }
 
@@ -464,42 +1369,223 @@ impl DebugBaseType {
         self.size
     }
 
+    pub fn endian(&self) -> gimli::RunTimeEndian {
+        self.endian
+    }
+
+    /// The `DW_AT_encoding` of this type, if it came from debug info rather
+    /// than [`DebugBaseType::new()`].
+    pub fn encoding(&self) -> Option<gimli::DwAte> {
+        self.encoding
+    }
+
+    /// The encoding of this type, decoded into a [`unit_info::BaseTypeEncoding`].
+    pub fn encoding_kind(&self) -> Option<unit_info::BaseTypeEncoding> {
+        self.encoding.map(unit_info::BaseTypeEncoding::from)
+    }
+
+    /// Whether the encoding is known and is something other than `encoding`.
+    fn encoding_is_not(&self, encoding: gimli::DwAte) -> bool {
+        self.encoding.is_some_and(|actual| actual != encoding)
+    }
+
     pub fn as_u8<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<u8> {
-        let address = self.location?.0;
-        Some(match self.size() {
-            1 => memory_source.read_u8(address).ok()?,
-            _ => return None,
-        })
+        if self.size() > 1 {
+            return None;
+        }
+        self.as_u64(memory_source)?.try_into().ok()
+    }
+
+    /// Read a one-byte boolean. As in C and Rust, any nonzero value is `true`.
+    pub fn as_bool<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<bool> {
+        if self.size() != 1 {
+            return None;
+        }
+        Some(self.as_u8(memory_source)? != 0)
+    }
+
+    /// Like [Self::as_bool], but also returns `None` if the encoding is known and isn't
+    /// a boolean, so that a `uint8_t` isn't mistaken for a flag.
+    pub fn as_bool_checked<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<bool> {
+        if self.encoding_is_not(gimli::constants::DW_ATE_boolean) {
+            return None;
+        }
+        self.as_bool(memory_source)
+    }
+
+    /// Read a one-byte boolean, returning `None` if the byte is anything other than
+    /// `0` or `1`. Useful for spotting uninitialized or corrupted memory.
+    pub fn as_bool_strict<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<bool> {
+        if self.size() != 1 {
+            return None;
+        }
+        match self.as_u8(memory_source)? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Read a Rust `char`, returning `None` if the value is not a valid Unicode
+    /// scalar value.
+    pub fn as_char<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<char> {
+        if self.size() != 4 || self.encoding_is_not(gimli::constants::DW_ATE_UTF) {
+            return None;
+        }
+        char::from_u32(self.as_u32(memory_source)?)
     }
 
     pub fn as_u16<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<u16> {
-        let address = self.location?.0;
-        Some(match self.size() {
-            1 => memory_source.read_u8(address).ok()?.into(),
-            2 => memory_source.read_u16(address).ok()?,
-            _ => return None,
-        })
+        if self.size() > 2 {
+            return None;
+        }
+        self.as_u64(memory_source)?.try_into().ok()
     }
 
     pub fn as_u32<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<u32> {
-        let address = self.location?.0;
-        Some(match self.size() {
-            1 => memory_source.read_u8(address).ok()?.into(),
-            2 => memory_source.read_u16(address).ok()?.into(),
-            4 => memory_source.read_u32(address).ok()?,
-            _ => return None,
-        })
+        if self.size() > 4 {
+            return None;
+        }
+        self.as_u64(memory_source)?.try_into().ok()
     }
 
     pub fn as_u64<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<u64> {
         let address = self.location?.0;
-        Some(match self.size() {
-            1 => memory_source.read_u8(address).ok()?.into(),
-            2 => memory_source.read_u16(address).ok()?.into(),
-            4 => memory_source.read_u32(address).ok()?.into(),
-            8 => memory_source.read_u64(address).ok()?,
-            _ => return None,
-        })
+        read_unsigned(memory_source, address, self.size(), self.endian).ok()
+    }
+
+    /// Read an integer of up to 16 bytes, such as a Rust `u128`.
+    pub fn as_u128<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<u128> {
+        if self.size() == 16 {
+            read_u128(memory_source, self.location?.0, self.endian).ok()
+        } else {
+            self.as_u64(memory_source).map(u128::from)
+        }
+    }
+
+    /// Read a signed integer of up to 16 bytes, such as a Rust `i128`. As with
+    /// [`DebugBaseType::as_i64()`], only signed types are sign-extended.
+    pub fn as_i128<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<i128> {
+        let value = self.as_u128(memory_source)?;
+        if !self.is_signed() {
+            return value.try_into().ok();
+        }
+        let shift = 128 - 8 * u32::try_from(self.size()).ok()?;
+        Some(((value << shift) as i128) >> shift)
+    }
+
+    /// Whether values of this type are sign-extended when read as a signed integer.
+    /// Types without an encoding, as created by [`DebugBaseType::new()`], are assumed
+    /// to be signed, since the caller asked for a signed value.
+    fn is_signed(&self) -> bool {
+        self.encoding_kind()
+            .is_none_or(|encoding| encoding.is_signed())
+    }
+
+    pub fn as_i8<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<i8> {
+        if self.size() > 1 {
+            return None;
+        }
+        self.as_i64(memory_source)?.try_into().ok()
+    }
+
+    pub fn as_i16<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<i16> {
+        if self.size() > 2 {
+            return None;
+        }
+        self.as_i64(memory_source)?.try_into().ok()
+    }
+
+    pub fn as_i32<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<i32> {
+        if self.size() > 4 {
+            return None;
+        }
+        self.as_i64(memory_source)?.try_into().ok()
+    }
+
+    /// Read a value as a signed integer. Signed types are sign-extended from the size
+    /// of the base type, so that e.g. a two-byte `0xfffe` is returned as `-2`, while
+    /// unsigned types are zero-extended, so that a `u8` of `0xff` is returned as `255`.
+    /// Unsigned values that don't fit are returned as `None`.
+    pub fn as_i64<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<i64> {
+        let value = self.as_u64(memory_source)?;
+        if !self.is_signed() {
+            return value.try_into().ok();
+        }
+        let shift = 64 - 8 * u32::try_from(self.size()).ok()?;
+        Some(((value << shift) as i64) >> shift)
+    }
+
+    pub fn as_f32<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<f32> {
+        if self.size() != 4 || self.encoding_is_not(gimli::constants::DW_ATE_float) {
+            return None;
+        }
+        Some(f32::from_bits(self.as_u32(memory_source)?))
+    }
+
+    /// Read a floating point value. Four-byte floats are widened to `f64`.
+    pub fn as_f64<S: Read + ?Sized>(&self, memory_source: &mut S) -> Option<f64> {
+        if self.size() == 4 {
+            return self.as_f32(memory_source).map(f64::from);
+        }
+        if self.size() != 8 || self.encoding_is_not(gimli::constants::DW_ATE_float) {
+            return None;
+        }
+        Some(f64::from_bits(self.as_u64(memory_source)?))
+    }
+
+    /// Read this value, picking a representation based on the encoding and size of the
+    /// type. Types created with [`DebugBaseType::new()`] have no encoding and are read
+    /// as unsigned. Anything that can't be represented otherwise is returned as
+    /// [`BaseValue::Bytes`].
+    pub fn value<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<BaseValue, DebugTypeError> {
+        let address = self.location()?;
+        let size = self.size();
+        if matches!(size, 1 | 2 | 4 | 8) {
+            let raw = read_unsigned(memory_source, address, size, self.endian)?;
+            let shift = 64 - 8 * size as u32;
+            match self.encoding {
+                None
+                | Some(
+                    gimli::constants::DW_ATE_unsigned
+                    | gimli::constants::DW_ATE_unsigned_char
+                    | gimli::constants::DW_ATE_address,
+                ) => {
+                    return Ok(BaseValue::Unsigned(raw));
+                }
+                Some(gimli::constants::DW_ATE_signed | gimli::constants::DW_ATE_signed_char) => {
+                    return Ok(BaseValue::Signed(((raw << shift) as i64) >> shift));
+                }
+                Some(gimli::constants::DW_ATE_boolean) if size == 1 => {
+                    return Ok(BaseValue::Bool(raw != 0));
+                }
+                Some(gimli::constants::DW_ATE_float) if size == 4 => {
+                    return Ok(BaseValue::Float(f32::from_bits(raw as u32).into()));
+                }
+                Some(gimli::constants::DW_ATE_float) if size == 8 => {
+                    return Ok(BaseValue::Float(f64::from_bits(raw)));
+                }
+                Some(gimli::constants::DW_ATE_UTF) if size == 4 => {
+                    if let Some(value) = char::from_u32(raw as u32) {
+                        return Ok(BaseValue::Char(value));
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+
+        let mut bytes = vec![];
+        for offset in 0..size {
+            bytes.push(
+                memory_source
+                    .read_u8(address + offset)
+                    .map_err(|_| DebugTypeError::ReadError)?,
+            );
+        }
+        Ok(BaseValue::Bytes(bytes))
     }
 
     pub fn location(&self) -> Result<u64, DebugTypeError> {
@@ -530,64 +1616,15 @@ pub struct DebugStructureMember<'a> {
 
 impl<'a> DebugStructureMember<'a> {
     fn find_alternatives(&self, attempted: &str) -> DebugTypeError {
-        let member = self.structure_member.name().map(|s| s.to_owned());
-        let attempted = attempted.to_owned();
-        let kind_index = self.structure_member.kind();
-        if self.info.structure_from_item(kind_index).is_some() {
-            DebugTypeError::KindIncorrect {
-                owner: self.parent_name.clone(),
-                member,
-                attempted,
-                actual: "structure".to_owned(),
-                path: self.path.clone(),
-            }
-        } else if self.info.enumeration_from_item(kind_index).is_some() {
-            DebugTypeError::KindIncorrect {
-                owner: self.parent_name.clone(),
-                member,
-                attempted,
-                actual: "enumeration".to_owned(),
-                path: self.path.clone(),
-            }
-        } else if self.info.pointer_from_item(kind_index).is_some() {
-            DebugTypeError::KindIncorrect {
-                owner: self.parent_name.clone(),
-                member,
-                attempted,
-                actual: "pointer".to_owned(),
-                path: self.path.clone(),
-            }
-        } else if self.info.array_from_item(kind_index).is_some() {
-            DebugTypeError::KindIncorrect {
-                owner: self.parent_name.clone(),
-                member,
-                attempted,
-                actual: "array".to_owned(),
-                path: self.path.clone(),
-            }
-        } else if self.info.union_from_item(kind_index).is_some() {
-            DebugTypeError::KindIncorrect {
-                owner: self.parent_name.clone(),
-                member,
-                attempted,
-                actual: "union".to_owned(),
-                path: self.path.clone(),
-            }
-        } else if self.info.base_type_from_item(kind_index).is_some() {
-            DebugTypeError::KindIncorrect {
-                owner: self.parent_name.clone(),
-                member,
-                attempted,
-                actual: "base type".to_owned(),
-                path: self.path.clone(),
-            }
-        } else {
-            DebugTypeError::KindNotFound {
-                owner: self.parent_name.clone(),
-                member,
-                path: self.path.clone(),
-            }
-        }
+        kind_mismatch(
+            self.info,
+            self.structure_member.kind(),
+            &self.parent_name,
+            self.structure_member.name(),
+            attempted,
+            &self.path,
+            self.location,
+        )
     }
 
     pub fn structure(&self) -> Result<DebugStructure<'a>, DebugTypeError> {
@@ -596,7 +1633,7 @@ impl<'a> DebugStructureMember<'a> {
             .map(|structure| DebugStructure {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + self.structure_member.offset()),
+                location: self.member_location(),
                 offset: self.offset + self.structure_member.offset(),
                 structure,
                 path: self.path.clone(),
@@ -610,7 +1647,7 @@ impl<'a> DebugStructureMember<'a> {
             .map(|enumeration| DebugEnumeration {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + self.structure_member.offset()),
+                location: self.member_location(),
                 offset: self.offset + self.structure_member.offset(),
                 enumeration,
                 path: self.path.clone(),
@@ -624,7 +1661,7 @@ impl<'a> DebugStructureMember<'a> {
             .map(|pointer| DebugPointer {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + self.structure_member.offset()),
+                location: self.member_location(),
                 offset: self.offset + self.structure_member.offset(),
                 pointer,
                 parent_name: self.parent_name.clone(),
@@ -633,13 +1670,28 @@ impl<'a> DebugStructureMember<'a> {
             .ok_or_else(|| self.find_alternatives("pointer"))
     }
 
+    /// The signature of a function pointer member. Function pointers are usually
+    /// described as a pointer to a subroutine type, so the pointer is followed if needed.
+    pub fn subroutine_type(&self) -> Result<&'a unit_info::SubroutineType, DebugTypeError> {
+        let kind = self.structure_member.kind();
+        self.info
+            .subroutine_type_from_item(kind)
+            .or_else(|| {
+                self.info
+                    .pointer_from_item(kind)
+                    .and_then(|pointer| pointer.kind())
+                    .and_then(|kind| self.info.subroutine_type_from_item(kind))
+            })
+            .ok_or_else(|| self.find_alternatives("subroutine type"))
+    }
+
     pub fn array(&self) -> Result<DebugArray<'a>, DebugTypeError> {
         self.info
             .array_from_item(self.structure_member.kind())
             .map(|array| DebugArray {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + self.structure_member.offset()),
+                location: self.member_location(),
                 offset: self.offset + self.structure_member.offset(),
                 array,
                 parent_name: self.parent_name.clone(),
@@ -654,7 +1706,7 @@ impl<'a> DebugStructureMember<'a> {
             .map(|union| DebugUnion {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + self.structure_member.offset()),
+                location: self.member_location(),
                 offset: self.offset + self.structure_member.offset(),
                 union,
                 path: self.path.clone(),
@@ -666,14 +1718,45 @@ impl<'a> DebugStructureMember<'a> {
         self.info
             .base_type_from_item(self.structure_member.kind())
             .map(|base_type| {
-                DebugBaseType::from_base_type(
-                    self.location.map(|l| l + self.structure_member.offset()),
-                    base_type,
-                )
+                DebugBaseType::from_base_type(self.member_location(), base_type, self.info.endian())
             })
             .ok_or_else(|| self.find_alternatives("base type"))
     }
 
+    /// The address of this member, or `None` if the parent has no address or the member
+    /// would lie beyond the end of the address space.
+    fn member_location(&self) -> Option<MemoryLocation> {
+        self.location
+            .and_then(|location| location.checked_add(self.structure_member.offset()))
+    }
+
+    /// The location and type of this member.
+    fn resolved(&self) -> (Option<MemoryLocation>, unit_info::DebugItem) {
+        (self.member_location(), self.structure_member.kind())
+    }
+
+    /// Treat this member as a structure or a union so that its members can be searched.
+    fn member_container(&self) -> Result<MemberContainer<'a>, DebugTypeError> {
+        if let Ok(union) = self.union() {
+            return Ok(MemberContainer::Union(union));
+        }
+        self.structure().map(MemberContainer::Structure)
+    }
+
+    /// This member as an item of any kind, for code that handles every kind of type.
+    pub(crate) fn as_item(&self) -> DebugArrayItem<'a> {
+        let (location, kind) = self.resolved();
+        DebugArrayItem {
+            unit: self.unit,
+            info: self.info,
+            location,
+            offset: self.offset,
+            kind,
+            parent_name: self.parent_name.clone(),
+            path: self.path.clone(),
+        }
+    }
+
     pub fn reset_offset(&mut self) -> &Self {
         self.offset = unit_info::StructOffset::new(0);
         self
@@ -682,7 +1765,12 @@ impl<'a> DebugStructureMember<'a> {
     pub fn location(&self) -> Result<u64, DebugTypeError> {
         self.location
             .ok_or(DebugTypeError::LocationMissing)
-            .map(|location| (location + self.structure_member.offset()).0)
+            .and_then(|location| {
+                location
+                    .checked_add(self.structure_member.offset())
+                    .ok_or(DebugTypeError::LocationMissing)
+            })
+            .map(|location| location.0)
     }
 }
 
@@ -694,40 +1782,89 @@ impl core::ops::Deref for DebugStructureMember<'_> {
     }
 }
 
-impl core::fmt::Debug for DebugStructureMember<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("DebugStructureMember")
-            .field("structure_member", &self.structure_member)
-            .finish()
+impl core::fmt::Debug for DebugStructureMember<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugStructureMember")
+            .field("structure_member", &self.structure_member)
+            .finish()
+    }
+}
+
+pub struct DebugGenericParameter<'a> {
+    info: &'a DebugInfo,
+    generic_parameter: &'a unit_info::GenericParameter,
+    path: String,
+}
+
+impl<'a> DebugGenericParameter<'a> {
+    pub fn structure(&self) -> Result<unit_info::Structure, DebugTypeError> {
+        self.info
+            .structure_from_item(self.generic_parameter.kind())
+            .cloned()
+            .ok_or(DebugTypeError::StructureNotFound {
+                owner: self
+                    .generic_parameter
+                    .name()
+                    .unwrap_or_default()
+                    .to_string(),
+                path: self.path.clone(),
+                location: None,
+            })
+    }
+
+    pub fn generic_parameter(&self) -> &'a unit_info::GenericParameter {
+        self.generic_parameter
+    }
+
+    pub fn name(&self) -> Option<&'a str> {
+        self.generic_parameter.name()
+    }
+
+    /// The concrete type that this parameter was instantiated with.
+    pub fn kind(&self) -> unit_info::DebugItem {
+        self.generic_parameter.kind()
+    }
+
+    /// The value of a value parameter, such as `N` in `heapless::Vec<T, N>`.
+    pub fn value(&self) -> Option<&'a unit_info::ConstValue> {
+        self.generic_parameter.value()
     }
-}
 
-pub struct DebugGenericParameter<'a> {
-    info: &'a DebugInfo,
-    generic_parameter: &'a unit_info::GenericParameter,
-    path: String,
-}
+    /// The size of the concrete type, if it can be determined.
+    pub fn size(&self) -> Option<StructOffset> {
+        self.info.size_from_item(self.generic_parameter.kind())
+    }
 
-impl<'a> DebugGenericParameter<'a> {
-    pub fn structure(&self) -> Result<unit_info::Structure, DebugTypeError> {
+    pub fn enumeration(&self) -> Result<&'a unit_info::Enumeration, DebugTypeError> {
         self.info
-            .structure_from_item(self.generic_parameter.kind())
-            .cloned()
-            .ok_or(DebugTypeError::StructureNotFound {
+            .enumeration_from_item(self.generic_parameter.kind())
+            .ok_or(DebugTypeError::EnumerationNotFound {
                 owner: self
                     .generic_parameter
                     .name()
                     .unwrap_or_default()
                     .to_string(),
                 path: self.path.clone(),
+                location: None,
             })
     }
 
-    pub fn generic_parameter(&self) -> &'a unit_info::GenericParameter {
-        self.generic_parameter
+    pub fn base_type(&self) -> Result<&'a unit_info::BaseType, DebugTypeError> {
+        self.info
+            .base_type_from_item(self.generic_parameter.kind())
+            .ok_or(DebugTypeError::BaseTypeNotFound {
+                owner: self
+                    .generic_parameter
+                    .name()
+                    .unwrap_or_default()
+                    .to_string(),
+                path: self.path.clone(),
+                location: None,
+            })
     }
 }
 
+#[derive(Clone)]
 pub struct DebugUnion<'a> {
     unit: &'a unit_info::UnitInfo,
     info: &'a DebugInfo,
@@ -743,18 +1880,36 @@ impl<'a> DebugUnion<'a> {
         unit: &'a unit_info::UnitInfo,
         info: &'a DebugInfo,
         union: &'a unit_info::Union,
-        location: unit_info::MemoryLocation,
+        location: Option<unit_info::MemoryLocation>,
     ) -> Self {
         Self {
             unit,
             info,
-            location: Some(location),
+            location,
             offset: unit_info::StructOffset::new(0),
             union,
             path: String::new(),
         }
     }
 
+    /// Iterate over every member of this union, in declaration order, ready to be read.
+    /// Unlike `members()`, which gives the raw [`unit_info::StructureMember`]s, each
+    /// member already has its location, and members without a name are included.
+    pub fn members_bound(&self) -> impl Iterator<Item = DebugStructureMember<'a>> + '_ {
+        self.union
+            .members()
+            .iter()
+            .map(|structure_member| DebugStructureMember {
+                unit: self.unit,
+                info: self.info,
+                location: self.location,
+                offset: self.offset + structure_member.offset(),
+                parent_name: self.union.name().into(),
+                structure_member,
+                path: make_path_name(&self.path, structure_member.name().unwrap_or("<anonymous>")),
+            })
+    }
+
     pub fn member_named(&self, name: &str) -> Result<DebugStructureMember<'a>, DebugTypeError> {
         self.union
             .member_named(name)
@@ -767,15 +1922,53 @@ impl<'a> DebugUnion<'a> {
                 structure_member,
                 path: make_path_name(&self.path, name),
             })
-            .ok_or_else(|| DebugTypeError::UnionNotFound {
+            .ok_or_else(|| DebugTypeError::MemberNotFound {
+                owner: self.union.name().to_string(),
+                member: name.into(),
+                available: member_names(self.union.members()),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
+    field_readers!();
+
+    /// Return the member at `index` in declaration order.
+    pub fn member_at(&self, index: usize) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        self.members_bound()
+            .nth(index)
+            .ok_or_else(|| DebugTypeError::MemberNotFound {
                 owner: self.union.name().to_string(),
+                member: format!("[{index}]"),
+                available: member_names(self.union.members()),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })
     }
 
+    /// Resolve a dotted member path such as `a.buf[3].x`. See
+    /// [`DebugStructure::member_path`].
+    pub fn member_path(&self, path: &str) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        MemberContainer::Union(self.clone()).member_path(path)
+    }
+
     pub fn location(&self) -> Option<unit_info::MemoryLocation> {
         self.location
     }
+
+    /// Place this union at a different address, keeping the same type.
+    pub fn with_address(mut self, address: u64) -> Self {
+        self.location = Some(unit_info::MemoryLocation(address));
+        self
+    }
+
+    /// Read the raw bytes of this union, covering its largest member.
+    pub fn read_bytes<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<Vec<u8>, DebugTypeError> {
+        read_bytes(memory_source, self.location, self.union.size())
+    }
 }
 
 impl core::fmt::Debug for DebugUnion<'_> {
@@ -785,12 +1978,21 @@ impl core::fmt::Debug for DebugUnion<'_> {
             .finish()
     }
 }
+
+impl core::ops::Deref for DebugUnion<'_> {
+    type Target = unit_info::Union;
+
+    fn deref(&self) -> &Self::Target {
+        self.union
+    }
+}
 pub struct DebugSliceBaseTypeIter<'a> {
     location: Option<unit_info::MemoryLocation>,
     length: u64,
     current: u64,
     size: unit_info::StructOffset,
     base_type: &'a unit_info::BaseType,
+    endian: gimli::RunTimeEndian,
 }
 
 impl DebugSliceBaseTypeIter<'_> {
@@ -809,10 +2011,11 @@ impl<'a> Iterator for DebugSliceBaseTypeIter<'a> {
         if self.current >= self.length {
             return None;
         }
-        let current = unit_info::StructOffset::new(self.current);
         let new = DebugBaseType::from_base_type(
-            self.location.map(|l| l + self.size * current),
+            self.location
+                .and_then(|location| element_location(location, self.size, self.current)),
             self.base_type,
+            self.endian,
         );
         self.current += 1;
         Some(new)
@@ -852,7 +2055,9 @@ impl<'a> Iterator for DebugSliceStructureIter<'a> {
         let new = DebugStructure {
             unit: self.unit,
             info: self.info,
-            location: self.location.map(|l| l + self.size * current),
+            location: self
+                .location
+                .and_then(|location| element_location(location, self.size, self.index)),
             offset: self.offset + self.size * current,
             structure: self.structure,
             path: make_path_name(&self.path, &format!("[{}]", self.index)),
@@ -862,14 +2067,50 @@ impl<'a> Iterator for DebugSliceStructureIter<'a> {
     }
 }
 
+/// Iterate over the elements of a slice, such as the enumerations in a `&[MyEnum]`.
+/// Each element is converted as it's reached, so each is a `Result`, and an element
+/// that can't be converted doesn't end the iteration early.
+pub struct DebugSliceIter<'a, T> {
+    slice: DebugSlice<'a>,
+    index: u64,
+    kind: unit_info::DebugItem,
+    stride: StructOffset,
+    convert: fn(&DebugArrayItem<'a>) -> Result<T, DebugTypeError>,
+}
+
+/// Iterate over the enumerations in a slice such as `&[MyEnum]`.
+pub type DebugSliceEnumerationIter<'a> = DebugSliceIter<'a, DebugEnumeration<'a>>;
+
+impl<T> Iterator for DebugSliceIter<'_, T> {
+    type Item = Result<T, DebugTypeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.slice.length {
+            return None;
+        }
+        let item = self.slice.item_at(self.index, self.kind, self.stride);
+        self.index += 1;
+        Some((self.convert)(&item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.slice.length - self.index).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for DebugSliceIter<'_, T> {}
+
 /// Wrap a Structure to include the unit that it came from
+#[derive(Clone)]
 pub struct DebugSlice<'a> {
     unit: &'a unit_info::UnitInfo,
     info: &'a DebugInfo,
     location: Option<unit_info::MemoryLocation>,
     offset: unit_info::StructOffset,
     length: u64,
-    data_ptr: &'a unit_info::Pointer,
+    /// The type of each element
+    element: Option<unit_info::DebugItem>,
     parent_name: String,
     /// The path we took to get here
     path: String,
@@ -877,17 +2118,23 @@ pub struct DebugSlice<'a> {
 
 impl<'a> DebugSlice<'a> {
     pub fn base_type_iter(&self) -> Result<DebugSliceBaseTypeIter<'a>, DebugTypeError> {
-        let Some(base_type) = self.info.base_type_from_item(self.data_ptr.kind()) else {
+        let Some(base_type) = self
+            .element
+            .and_then(|kind| self.info.base_type_from_item(kind))
+        else {
             return Err(DebugTypeError::BaseTypeNotFound {
                 owner: self.parent_name.clone(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             });
         };
-        let Some(element_size) = self.info.size_from_item(self.data_ptr.kind()) else {
+        let Some(element_size) = self.element.and_then(|kind| self.info.size_from_item(kind))
+        else {
             return Err(DebugTypeError::KindNotFound {
                 owner: "<todo>".into(),
                 member: None,
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             });
         };
         Ok(DebugSliceBaseTypeIter {
@@ -896,24 +2143,27 @@ impl<'a> DebugSlice<'a> {
             current: 0,
             size: element_size,
             base_type,
+            endian: self.info.endian(),
         })
     }
 
     pub fn structure_iter(&self) -> Result<DebugSliceStructureIter<'a>, DebugTypeError> {
         let structure = self
-            .info
-            .structure_from_item(self.data_ptr.kind())
+            .element
+            .and_then(|kind| self.info.structure_from_item(kind))
             .ok_or_else(|| DebugTypeError::StructureNotFound {
                 owner: self.parent_name.clone(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })?;
         let element_size = self
-            .info
-            .size_from_item(self.data_ptr.kind())
+            .element
+            .and_then(|kind| self.info.size_from_item(kind))
             .ok_or_else(|| DebugTypeError::KindNotFound {
                 owner: self.parent_name.clone(),
                 member: None,
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })?;
 
         Ok(DebugSliceStructureIter {
@@ -928,6 +2178,170 @@ impl<'a> DebugSlice<'a> {
             path: self.path.clone(),
         })
     }
+
+    /// The type of each element, and the distance from one element to the next.
+    fn element_and_stride(&self) -> Result<(unit_info::DebugItem, StructOffset), DebugTypeError> {
+        self.element
+            .and_then(|kind| Some((kind, StructOffset::new(self.info.byte_size(kind)?))))
+            .ok_or_else(|| DebugTypeError::KindNotFound {
+                owner: self.parent_name.clone(),
+                member: None,
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
+    /// Build the element at `index`, which must already have been checked against the
+    /// length of the slice.
+    fn item_at(
+        &self,
+        index: u64,
+        kind: unit_info::DebugItem,
+        stride: StructOffset,
+    ) -> DebugArrayItem<'a> {
+        DebugArrayItem {
+            unit: self.unit,
+            info: self.info,
+            location: self
+                .location
+                .and_then(|location| element_location(location, stride, index)),
+            offset: self.offset,
+            kind,
+            parent_name: self.parent_name.clone(),
+            path: make_path_name(&self.path, &format!("[{index}]")),
+        }
+    }
+
+    /// Return the element at `index` without walking the elements that come before it.
+    pub fn get(&self, index: usize) -> Result<DebugArrayItem<'a>, DebugTypeError> {
+        if index as u64 >= self.length {
+            return Err(DebugTypeError::IndexOutOfBounds {
+                owner: self.parent_name.clone(),
+                index,
+                length: self.length as usize,
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        }
+        let (kind, stride) = self.element_and_stride()?;
+        Ok(self.item_at(index as u64, kind, stride))
+    }
+
+    /// Make an iterator that converts each element with `convert`, after checking that
+    /// the elements are of the kind that `matches` looks for.
+    fn iter_as<T>(
+        &self,
+        attempted: &str,
+        matches: fn(&DebugInfo, unit_info::DebugItem) -> bool,
+        convert: fn(&DebugArrayItem<'a>) -> Result<T, DebugTypeError>,
+    ) -> Result<DebugSliceIter<'a, T>, DebugTypeError> {
+        let (element, stride) = self.element_and_stride()?;
+        if !matches(self.info, element) {
+            return Err(kind_mismatch(
+                self.info,
+                element,
+                &self.parent_name,
+                None,
+                attempted,
+                &self.path,
+                self.location,
+            ));
+        }
+        Ok(DebugSliceIter {
+            slice: self.clone(),
+            index: 0,
+            kind: element,
+            stride,
+            convert,
+        })
+    }
+
+    pub fn enumeration_iter(&self) -> Result<DebugSliceEnumerationIter<'a>, DebugTypeError> {
+        self.iter_as(
+            "enumeration",
+            |info, kind| info.enumeration_from_item(kind).is_some(),
+            DebugArrayItem::enumeration,
+        )
+    }
+
+    pub fn pointer_iter(&self) -> Result<DebugSliceIter<'a, DebugPointer<'a>>, DebugTypeError> {
+        self.iter_as(
+            "pointer",
+            |info, kind| info.pointer_from_item(kind).is_some(),
+            DebugArrayItem::pointer,
+        )
+    }
+
+    pub fn union_iter(&self) -> Result<DebugSliceIter<'a, DebugUnion<'a>>, DebugTypeError> {
+        self.iter_as(
+            "union",
+            |info, kind| info.union_from_item(kind).is_some(),
+            DebugArrayItem::union,
+        )
+    }
+
+    /// Read the contents of a `&str` or `&[u8]` as a string. Invalid UTF-8
+    /// sequences are replaced with `U+FFFD`. Slices longer than
+    /// [`MAX_STR_LENGTH`] bytes are rejected with [`DebugTypeError::SizeError`]; use
+    /// [`DebugSlice::as_string_lossy()`] to choose a different limit.
+    pub fn read_as_str<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<String, DebugTypeError> {
+        self.as_string_lossy(memory_source, MAX_STR_LENGTH)
+    }
+
+    /// Read the contents of a `&[u8]` in a single burst. The slice's length comes from
+    /// target memory, so anything longer than `max_length` is rejected with
+    /// [`DebugTypeError::SizeError`] rather than read.
+    pub fn read_bytes<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+        max_length: u64,
+    ) -> Result<Vec<u8>, DebugTypeError> {
+        let (element, _) = self.element_and_stride()?;
+        let Some(base_type) = self.info.base_type_from_item(element) else {
+            return Err(kind_mismatch(
+                self.info,
+                element,
+                &self.parent_name,
+                None,
+                "base type",
+                &self.path,
+                self.location,
+            ));
+        };
+        if base_type.size() != 1 {
+            return Err(DebugTypeError::SizeError(base_type.size()));
+        }
+        if self.length > max_length {
+            return Err(DebugTypeError::SizeError(self.length));
+        }
+        // Empty slices may have a dangling data pointer, so don't try to read from it.
+        if self.length == 0 {
+            return Ok(vec![]);
+        }
+        if self.location == Some(MemoryLocation(0)) {
+            return Err(DebugTypeError::NullPointer {
+                owner: self.parent_name.clone(),
+                path: self.path.clone(),
+                location: None,
+            });
+        }
+        read_bytes(memory_source, self.location, self.length)
+    }
+
+    /// Read the contents of a `&str` or `&[u8]` as a string, in a single burst. Slices
+    /// longer than `max_length` bytes are rejected with [`DebugTypeError::SizeError`]
+    /// rather than read, and invalid UTF-8 sequences are replaced with `U+FFFD`.
+    pub fn as_string_lossy<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+        max_length: u64,
+    ) -> Result<String, DebugTypeError> {
+        let data = self.read_bytes(memory_source, max_length)?;
+        Ok(String::from_utf8_lossy(&data).into_owned())
+    }
 }
 
 /// Wrap a Structure to include the unit that it came from
@@ -947,12 +2361,12 @@ impl<'a> DebugStructure<'a> {
         unit: &'a unit_info::UnitInfo,
         info: &'a DebugInfo,
         structure: &'a unit_info::Structure,
-        location: unit_info::MemoryLocation,
+        location: Option<unit_info::MemoryLocation>,
     ) -> Self {
         DebugStructure {
             unit,
             info,
-            location: Some(location),
+            location,
             offset: unit_info::StructOffset::new(0),
             structure,
             path: "".to_owned(),
@@ -974,23 +2388,39 @@ impl<'a> DebugStructure<'a> {
             .ok_or_else(|| DebugTypeError::MemberNotFound {
                 owner: self.structure.name().into(),
                 member: name.into(),
-                available: self
-                    .structure
-                    .members()
-                    .iter()
-                    .enumerate()
-                    .map(|(index, member)| {
-                        member
-                            .name()
-                            .map(|n| n.to_owned())
-                            .unwrap_or_else(|| format!("anonymous[{index}]"))
-                    })
-                    .collect(),
+                available: member_names(self.structure.members()),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
+    field_readers!();
+
+    /// Return the member at `index` in declaration order. This reaches members that
+    /// have no name, such as the fields of a tuple struct.
+    pub fn member_at(&self, index: usize) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        self.members_bound()
+            .nth(index)
+            .ok_or_else(|| DebugTypeError::MemberNotFound {
+                owner: self.structure.name().into(),
+                member: format!("[{index}]"),
+                available: member_names(self.structure.members()),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })
     }
 
-    pub fn members(&self) -> Vec<DebugStructureMember<'a>> {
+    /// Resolve a dotted member path such as `a.buf[3].x`, descending through any
+    /// structures, unions and arrays along the way, and return the final member.
+    /// Multi-dimensional arrays take one index per dimension, as in `grid[1][2]`.
+    pub fn member_path(&self, path: &str) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        MemberContainer::Structure(self.clone()).member_path(path)
+    }
+
+    /// Iterate over every member of this structure, in declaration order, ready to be
+    /// read. Unlike `members()`, which gives the raw [`unit_info::StructureMember`]s,
+    /// each member already has its location, and members without a name are included.
+    pub fn members_bound(&self) -> impl Iterator<Item = DebugStructureMember<'a>> + '_ {
         self.structure
             .members()
             .iter()
@@ -1003,7 +2433,6 @@ impl<'a> DebugStructure<'a> {
                 structure_member,
                 path: make_path_name(&self.path, structure_member.name().unwrap_or("<anonymous>")),
             })
-            .collect()
     }
 
     pub fn generics(&self) -> Vec<DebugGenericParameter<'a>> {
@@ -1018,6 +2447,22 @@ impl<'a> DebugStructure<'a> {
             .collect()
     }
 
+    /// Return the generic parameter with the given name, such as `T`.
+    pub fn generic_named(&self, name: &str) -> Result<DebugGenericParameter<'a>, DebugTypeError> {
+        self.structure
+            .generic_named(name)
+            .map(|generic_parameter| DebugGenericParameter {
+                info: self.info,
+                generic_parameter,
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| DebugTypeError::GenericNotFound {
+                owner: self.structure.name().to_owned(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
     /// Special case for Rust slices, which always have two members:
     /// a "data_ptr" and a "length".
     pub fn as_slice<S: Read + ?Sized>(
@@ -1028,6 +2473,7 @@ impl<'a> DebugStructure<'a> {
             return Err(DebugTypeError::NotRustSlice {
                 owner: self.structure.name().into(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             });
         }
         let length = self
@@ -1045,16 +2491,211 @@ impl<'a> DebugStructure<'a> {
             location: data_ptr.location,
             offset: self.offset,
             length,
-            data_ptr: data_ptr.pointer,
+            element: data_ptr.pointer.kind(),
+            parent_name: self.structure.name().to_string(),
+            path: self.path.clone(),
+        })
+    }
+
+    /// Read the contents of a `&str` or an owned `String`. Strings longer than
+    /// `max_length` bytes are rejected with [`DebugTypeError::SizeError`], which guards
+    /// against reading huge amounts of memory when the length is garbage. Invalid UTF-8
+    /// sequences are replaced with `U+FFFD`.
+    pub fn as_rust_string<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+        max_length: u64,
+    ) -> Result<String, DebugTypeError> {
+        if self.structure.member_named("data_ptr").is_some() {
+            return self
+                .as_slice(memory_source)?
+                .as_string_lossy(memory_source, max_length);
+        }
+
+        let Ok(vec) = self.member_named("vec").and_then(|vec| vec.structure()) else {
+            return Err(DebugTypeError::KindIncorrect {
+                owner: self.structure.name().into(),
+                member: None,
+                attempted: "String".into(),
+                actual: "structure".into(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        };
+        let slice = match vec.as_vec(memory_source) {
+            Err(DebugTypeError::UnrecognizedLayout { .. }) => vec.as_inline_vec(memory_source)?,
+            slice => slice?,
+        };
+        slice.as_string_lossy(memory_source, max_length)
+    }
+
+    /// Treat this structure as a `Vec<T>` and return its contents as a slice of `T`.
+    pub fn as_vec<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<DebugSlice<'a>, DebugTypeError> {
+        // The data pointer is buried in several layers of wrappers whose names
+        // change between compiler versions. Newer versions also erase the element
+        // type from the pointer, so it is taken from the generic parameter instead.
+        const DATA_POINTER_PATHS: [&str; 3] = [
+            "buf.inner.ptr.pointer.pointer",
+            "buf.ptr.pointer.pointer",
+            "buf.ptr.pointer",
+        ];
+        let Some(pointer) = DATA_POINTER_PATHS
+            .iter()
+            .find_map(|path| self.member_path(path).and_then(|m| m.pointer()).ok())
+        else {
+            return Err(DebugTypeError::UnrecognizedLayout {
+                owner: self.structure.name().into(),
+                attempted: DATA_POINTER_PATHS.iter().map(|p| p.to_string()).collect(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        };
+        let length = self.read_field_u64("len", memory_source)?;
+        let element = self
+            .generic_named("T")
+            .map(|generic| generic.kind())
+            .ok()
+            .or_else(|| pointer.pointer.kind());
+        let data = pointer.follow(memory_source)?;
+        Ok(DebugSlice {
+            unit: self.unit,
+            info: self.info,
+            location: data.location,
+            offset: self.offset,
+            length,
+            element,
+            parent_name: self.structure.name().to_string(),
+            path: self.path.clone(),
+        })
+    }
+
+    /// Treat this structure as a vector whose elements are stored inline, such as
+    /// `heapless::Vec<T, N>`, and return the elements that are in use as a slice.
+    /// A `heapless::String<N>` is also accepted and returns its bytes.
+    pub fn as_inline_vec<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<DebugSlice<'a>, DebugTypeError> {
+        if let Ok(vec) = self.member_named("vec").and_then(|vec| vec.structure()) {
+            return vec.as_inline_vec(memory_source);
+        }
+
+        const LAYOUTS: [(&str, &str); 2] = [("len", "buffer"), ("inner.len", "inner.buffer")];
+        let Some((length, buffer)) = LAYOUTS.iter().find_map(|(length, buffer)| {
+            let length = self.member_path(length).and_then(|m| m.base_type()).ok()?;
+            let buffer = self.member_path(buffer).and_then(|m| m.array()).ok()?;
+            Some((length, buffer))
+        }) else {
+            return Err(DebugTypeError::UnrecognizedLayout {
+                owner: self.structure.name().into(),
+                attempted: LAYOUTS
+                    .iter()
+                    .map(|(length, buffer)| format!("{length} and {buffer}"))
+                    .collect(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        };
+        let length = length
+            .as_u64(memory_source)
+            .ok_or(DebugTypeError::ReadError)?
+            .min(buffer.count() as u64);
+        // The buffer holds `MaybeUninit<T>`, so prefer `T` itself when it is known.
+        let element = self
+            .generic_named("T")
+            .map(|generic| generic.kind())
+            .unwrap_or(buffer.kind());
+        Ok(DebugSlice {
+            unit: self.unit,
+            info: self.info,
+            location: buffer.location,
+            offset: buffer.offset,
+            length,
+            element: Some(element),
             parent_name: self.structure.name().to_string(),
             path: self.path.clone(),
         })
     }
 
+    /// Special case for Rust trait objects such as `&dyn Trait` or `Box<dyn Trait>`, which
+    /// have a "pointer" to the data and a "vtable". The vtable is used to find the
+    /// concrete type, which is returned positioned at the data.
+    pub fn as_trait_object<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<DebugStructure<'a>, DebugTypeError> {
+        let (Some(pointer), Some(vtable)) = (
+            self.structure.member_named("pointer"),
+            self.structure.member_named("vtable"),
+        ) else {
+            return Err(DebugTypeError::NotTraitObject {
+                owner: self.structure.name().into(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        };
+        let location = self.location.ok_or(DebugTypeError::LocationMissing)?;
+        let address_size = self.unit.address_size.into();
+        let data = read_unsigned(
+            memory_source,
+            location
+                .checked_add(pointer.offset())
+                .ok_or(DebugTypeError::LocationMissing)?
+                .0,
+            address_size,
+            self.info.endian(),
+        )?;
+        let vtable = read_unsigned(
+            memory_source,
+            location
+                .checked_add(vtable.offset())
+                .ok_or(DebugTypeError::LocationMissing)?
+                .0,
+            address_size,
+            self.info.endian(),
+        )?;
+        let concrete =
+            self.info
+                .type_from_vtable(vtable)
+                .ok_or_else(|| DebugTypeError::VtableNotFound {
+                    owner: self.structure.name().into(),
+                    address: vtable,
+                    path: self.path.clone(),
+                    location: self.location.map(|location| location.0),
+                })?;
+        let mut structure = self.info.structure_from_item_at_address(&concrete, data)?;
+        structure.path = self.path.clone();
+        Ok(structure)
+    }
+
     pub fn location(&self) -> Option<unit_info::MemoryLocation> {
         self.location
     }
 
+    /// Place this structure at a different address, keeping the same type. This is useful
+    /// when walking a linked list or a table of objects that share a layout.
+    pub fn with_address(mut self, address: u64) -> Self {
+        self.location = Some(unit_info::MemoryLocation(address));
+        self
+    }
+
+    /// Read the raw bytes of this structure, including any padding, for example to
+    /// checksum it or to hand it to another parser.
+    pub fn read_bytes<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<Vec<u8>, DebugTypeError> {
+        // Declarations have no size, which is reported as a size error.
+        read_bytes(
+            memory_source,
+            self.location,
+            self.structure.size().unwrap_or(0),
+        )
+    }
+
     pub fn structure(&self) -> &unit_info::Structure {
         self.structure
     }
@@ -1096,49 +2737,169 @@ pub struct DebugPointer<'a> {
     path: String,
 }
 
-impl<'a> DebugPointer<'a> {
-    pub fn structure(&self) -> Result<DebugStructure<'a>, DebugTypeError> {
+impl<'a> DebugPointer<'a> {
+    /// The type that this pointer points to, or an error if this is a `void *`.
+    fn pointee(&self) -> Result<unit_info::DebugItem, DebugTypeError> {
+        self.pointer
+            .kind()
+            .ok_or_else(|| DebugTypeError::VoidPointer {
+                owner: self.parent_name.clone(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
+    pub fn structure(&self) -> Result<DebugStructure<'a>, DebugTypeError> {
+        let pointee = self.pointee()?;
+        self.info
+            .structure_from_item(pointee)
+            .map(|structure| DebugStructure {
+                unit: self.unit,
+                structure,
+                location: self.location,
+                offset: self.offset,
+                info: self.info,
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| self.find_alternatives(pointee, "structure"))
+    }
+
+    /// Describe what the pointee actually is, for when it isn't the kind that was asked for.
+    fn find_alternatives(&self, pointee: unit_info::DebugItem, attempted: &str) -> DebugTypeError {
+        kind_mismatch(
+            self.info,
+            pointee,
+            &self.parent_name,
+            self.path.rsplit('.').next(),
+            attempted,
+            &self.path,
+            self.location,
+        )
+    }
+
+    pub fn base_type(&self) -> Result<DebugBaseType, DebugTypeError> {
+        let pointee = self.pointee()?;
+        self.info
+            .base_type_from_item(pointee)
+            .map(|base_type| {
+                DebugBaseType::from_base_type(self.location, base_type, self.info.endian())
+            })
+            .ok_or_else(|| self.find_alternatives(pointee, "base type"))
+    }
+
+    pub fn enumeration(&self) -> Result<DebugEnumeration<'a>, DebugTypeError> {
+        let pointee = self.pointee()?;
         self.info
-            .structure_from_item(self.pointer.kind())
-            .map(|structure| DebugStructure {
+            .enumeration_from_item(pointee)
+            .map(|enumeration| DebugEnumeration {
                 unit: self.unit,
-                structure,
+                info: self.info,
                 location: self.location,
                 offset: self.offset,
-                info: self.info,
+                enumeration,
                 path: self.path.clone(),
             })
-            .ok_or_else(|| DebugTypeError::StructureNotFound {
-                owner: self.parent_name.clone(),
+            .ok_or_else(|| self.find_alternatives(pointee, "enumeration"))
+    }
+
+    pub fn union(&self) -> Result<DebugUnion<'a>, DebugTypeError> {
+        let pointee = self.pointee()?;
+        self.info
+            .union_from_item(pointee)
+            .map(|union| DebugUnion {
+                unit: self.unit,
+                info: self.info,
+                location: self.location,
+                offset: self.offset,
+                union,
                 path: self.path.clone(),
             })
+            .ok_or_else(|| self.find_alternatives(pointee, "union"))
     }
 
-    pub fn base_type(&self) -> Result<DebugBaseType, DebugTypeError> {
+    pub fn array(&self) -> Result<DebugArray<'a>, DebugTypeError> {
+        let pointee = self.pointee()?;
         self.info
-            .base_type_from_item(self.pointer.kind())
-            .map(|base_type| DebugBaseType {
-                size: base_type.size(),
-                name: base_type.name().to_owned(),
+            .array_from_item(pointee)
+            .map(|array| DebugArray {
+                unit: self.unit,
+                info: self.info,
                 location: self.location,
+                offset: self.offset,
+                array,
+                parent_name: self.parent_name.clone(),
+                path: self.path.clone(),
             })
-            .ok_or_else(|| DebugTypeError::BaseTypeNotFound {
-                owner: self.parent_name.clone(),
+            .ok_or_else(|| self.find_alternatives(pointee, "array"))
+    }
+
+    /// The pointer that this pointer points to, as in `T **`.
+    pub fn pointer(&self) -> Result<DebugPointer<'a>, DebugTypeError> {
+        let pointee = self.pointee()?;
+        self.info
+            .pointer_from_item(pointee)
+            .map(|pointer| DebugPointer {
+                unit: self.unit,
+                info: self.info,
+                location: self.location,
+                offset: self.offset,
+                pointer,
+                parent_name: self.parent_name.clone(),
                 path: self.path.clone(),
             })
+            .ok_or_else(|| self.find_alternatives(pointee, "pointer"))
+    }
+
+    /// The value that this pointer points to, once it has been followed. Returns `None`
+    /// for `void *` and function pointers, which have nothing that can be read.
+    pub(crate) fn pointee_item(&self) -> Option<DebugArrayItem<'a>> {
+        let kind = self.pointer.kind()?;
+        if self.info.subroutine_type_from_item(kind).is_some() {
+            return None;
+        }
+        Some(DebugArrayItem {
+            unit: self.unit,
+            info: self.info,
+            location: self.location,
+            offset: self.offset,
+            kind,
+            parent_name: self.parent_name.clone(),
+            path: self.path.clone(),
+        })
     }
 
     pub fn follow_unless_null<S: Read + ?Sized>(
         self,
         memory_source: &mut S,
     ) -> Result<Self, DebugTypeError> {
-        let new = self.follow(memory_source)?;
-        let location = &new.location.ok_or(DebugTypeError::ReadError)?;
-        if *location == MemoryLocation(0) {
-            Err(DebugTypeError::ReadError)
-        } else {
-            Ok(new)
+        let target = self.target_address(memory_source)?;
+        if target == 0 {
+            return Err(DebugTypeError::NullPointer {
+                owner: self.parent_name.clone(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
         }
+        Ok(self.at_target(target))
+    }
+
+    /// Read the address that this pointer holds, without following it.
+    pub fn target_address<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<u64, DebugTypeError> {
+        let location = self.location.ok_or(DebugTypeError::LocationMissing)?.0;
+        read_unsigned(
+            memory_source,
+            location,
+            self.unit.address_size.into(),
+            self.info.endian(),
+        )
+    }
+
+    /// Whether this pointer is null.
+    pub fn is_null<S: Read + ?Sized>(&self, memory_source: &mut S) -> Result<bool, DebugTypeError> {
+        Ok(self.target_address(memory_source)? == 0)
     }
 
     /// Attempt to follow a pointer. If the pointer is null, return `Ok(None)`.
@@ -1146,34 +2907,100 @@ impl<'a> DebugPointer<'a> {
         self,
         memory_source: &mut S,
     ) -> Result<Option<Self>, DebugTypeError> {
-        let new = self.follow(memory_source)?;
-        let location = &new.location.ok_or(DebugTypeError::ReadError)?;
-        if *location == MemoryLocation(0) {
-            Ok(None)
-        } else {
-            Ok(Some(new))
-        }
+        let target = self.target_address(memory_source)?;
+        Ok((target != 0).then(|| self.at_target(target)))
     }
 
-    pub fn follow<S: Read + ?Sized>(
-        mut self,
+    /// Point at `target`, which is the address that this pointer holds.
+    fn at_target(mut self, target: u64) -> Self {
+        self.location = Some(MemoryLocation(target));
+        self.offset = StructOffset::new(0);
+        self
+    }
+
+    /// Follow this pointer, reading an address as wide as the target's pointers.
+    pub fn follow<S: Read + ?Sized>(self, memory_source: &mut S) -> Result<Self, DebugTypeError> {
+        let address_size = self.unit.address_size;
+        self.follow_with_address_size(address_size, memory_source)
+    }
+
+    /// Follow a pointer that is stored as a full 64-bit address, regardless of the
+    /// address size of the target. This is useful for 32-bit targets that store
+    /// absolute addresses in wider fields.
+    pub fn follow_u64<S: Read + ?Sized>(
+        self,
+        memory_source: &mut S,
+    ) -> Result<Self, DebugTypeError> {
+        self.follow_with_address_size(8, memory_source)
+    }
+
+    /// Follow a pointer whose value is `address_size` bytes wide. The value is
+    /// decoded using the byte order of the target. Only 2-, 4- and 8-byte addresses
+    /// are supported.
+    pub fn follow_with_address_size<S: Read + ?Sized>(
+        self,
+        address_size: u8,
         memory_source: &mut S,
     ) -> Result<Self, DebugTypeError> {
+        if !matches!(address_size, 2 | 4 | 8) {
+            return Err(DebugTypeError::SizeError(address_size.into()));
+        }
         let location = self.location.ok_or(DebugTypeError::LocationMissing)?.0;
-        let target = memory_source
-            .read_u32(location)
-            .map_err(|_| DebugTypeError::ReadError)?;
-        self.location = Some(MemoryLocation(target.into()));
-        self.offset = StructOffset::new(0);
-        Ok(self)
+        let target = read_unsigned(
+            memory_source,
+            location,
+            address_size.into(),
+            self.info.endian(),
+        )?;
+        Ok(self.at_target(target))
+    }
+
+    /// Follow this pointer and read the NUL-terminated string it points to, such as
+    /// a C `const char *`. At most `max_length` bytes are read.
+    pub fn read_cstring<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+        max_length: usize,
+    ) -> Result<CStringValue, DebugTypeError> {
+        let target = self.target_address(memory_source)?;
+        if target == 0 {
+            return Err(DebugTypeError::NullPointer {
+                owner: self.parent_name.clone(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        }
+        read_cstring(memory_source, target, max_length)
     }
 
     /// Read a u8 from the specified offset
     pub fn read_u8<S: Read + ?Sized>(&self, offset: u64, memory_source: &mut S) -> Option<u8> {
-        let location = self.location?.0 + offset;
+        let location = self.location?.0.checked_add(offset)?;
         memory_source.read_u8(location).ok()
     }
 
+    /// Read a u16 from the specified offset, in the target's byte order
+    pub fn read_u16<S: Read + ?Sized>(&self, offset: u64, memory_source: &mut S) -> Option<u16> {
+        let location = self.location?.0.checked_add(offset)?;
+        read_unsigned(memory_source, location, 2, self.info.endian())
+            .ok()
+            .map(|value| value as u16)
+    }
+
+    /// Read a u32 from the specified offset, in the target's byte order
+    pub fn read_u32<S: Read + ?Sized>(&self, offset: u64, memory_source: &mut S) -> Option<u32> {
+        let location = self.location?.0.checked_add(offset)?;
+        read_unsigned(memory_source, location, 4, self.info.endian())
+            .ok()
+            .map(|value| value as u32)
+    }
+
+    /// Read a u64 from the specified offset, in the target's byte order
+    pub fn read_u64<S: Read + ?Sized>(&self, offset: u64, memory_source: &mut S) -> Option<u64> {
+        let location = self.location?.0.checked_add(offset)?;
+        read_unsigned(memory_source, location, 8, self.info.endian()).ok()
+    }
+
     pub fn location(&self) -> Result<u64, DebugTypeError> {
         self.location
             .ok_or(DebugTypeError::LocationMissing)
@@ -1217,7 +3044,9 @@ impl<'a> DebugEnumerationVariant<'a> {
             .map(|structure| DebugStructure {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + self.variant.offset()),
+                location: self
+                    .location
+                    .and_then(|l| l.checked_add(self.variant.offset())),
                 offset: self.offset + self.variant.offset(),
                 structure,
                 path: self.path.clone(),
@@ -1225,7 +3054,145 @@ impl<'a> DebugEnumerationVariant<'a> {
             .ok_or_else(|| DebugTypeError::StructureNotFound {
                 owner: self.parent_name.clone(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
+    /// Return the field at `index` of a tuple variant, such as the value inside `Some(_)`.
+    /// rustc names these fields `__0`, `__1` and so on, but they are found by position
+    /// so the name doesn't matter.
+    pub fn field(&self, index: usize) -> Result<DebugStructureMember<'a>, DebugTypeError> {
+        self.structure()?.member_at(index)
+    }
+
+    fn find_alternatives(&self, kind: unit_info::DebugItem, attempted: &str) -> DebugTypeError {
+        kind_mismatch(
+            self.info,
+            kind,
+            &self.parent_name,
+            Some(self.variant.name()),
+            attempted,
+            &self.path,
+            self.location,
+        )
+    }
+
+    /// The value held by this variant as an item of any kind, positioned the same way
+    /// as [`DebugEnumerationVariant::structure()`].
+    pub(crate) fn as_item(&self) -> DebugArrayItem<'a> {
+        DebugArrayItem {
+            unit: self.unit,
+            info: self.info,
+            location: self
+                .location
+                .and_then(|l| l.checked_add(self.variant.offset())),
+            offset: self.offset + self.variant.offset(),
+            kind: self.variant.kind(),
+            parent_name: self.parent_name.clone(),
+            path: self.path.clone(),
+        }
+    }
+
+    /// The type, location and offset of the value that this variant holds. Rust wraps
+    /// the value of a tuple variant such as `Some(u32)` in a structure with a single
+    /// field `__0`, so that field is used in place of the structure.
+    fn payload(
+        &self,
+    ) -> (
+        unit_info::DebugItem,
+        Option<unit_info::MemoryLocation>,
+        unit_info::StructOffset,
+    ) {
+        let location = self
+            .location
+            .and_then(|l| l.checked_add(self.variant.offset()));
+        let offset = self.offset + self.variant.offset();
+        if let Some(structure) = self.info.structure_from_item(self.variant.kind())
+            && let [field] = structure.members()
+        {
+            return (
+                field.kind(),
+                location.and_then(|l| l.checked_add(field.offset())),
+                offset + field.offset(),
+            );
+        }
+        (self.variant.kind(), location, offset)
+    }
+
+    /// The value of this variant as a base type, such as the `u32` in `Some(u32)`.
+    pub fn base_type(&self) -> Result<DebugBaseType, DebugTypeError> {
+        let (kind, location, _) = self.payload();
+        self.info
+            .base_type_from_item(kind)
+            .map(|base_type| DebugBaseType::from_base_type(location, base_type, self.info.endian()))
+            .ok_or_else(|| self.find_alternatives(kind, "base type"))
+    }
+
+    /// The value of this variant as a pointer, such as the `*mut T` in `Some(*mut T)`.
+    pub fn pointer(&self) -> Result<DebugPointer<'a>, DebugTypeError> {
+        let (kind, location, offset) = self.payload();
+        self.info
+            .pointer_from_item(kind)
+            .map(|pointer| DebugPointer {
+                unit: self.unit,
+                info: self.info,
+                location,
+                offset,
+                pointer,
+                parent_name: self.parent_name.clone(),
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| self.find_alternatives(kind, "pointer"))
+    }
+
+    /// The value of this variant as an array.
+    pub fn array(&self) -> Result<DebugArray<'a>, DebugTypeError> {
+        let (kind, location, offset) = self.payload();
+        self.info
+            .array_from_item(kind)
+            .map(|array| DebugArray {
+                unit: self.unit,
+                info: self.info,
+                location,
+                offset,
+                array,
+                parent_name: self.parent_name.clone(),
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| self.find_alternatives(kind, "array"))
+    }
+
+    /// The value of this variant as a union.
+    pub fn union(&self) -> Result<DebugUnion<'a>, DebugTypeError> {
+        let (kind, location, offset) = self.payload();
+        self.info
+            .union_from_item(kind)
+            .map(|union| DebugUnion {
+                unit: self.unit,
+                info: self.info,
+                location,
+                offset,
+                union,
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| self.find_alternatives(kind, "union"))
+    }
+
+    /// The value of this variant as an enumeration, such as the inner `Option` in
+    /// `Some(Option<u8>)`.
+    pub fn enumeration(&self) -> Result<DebugEnumeration<'a>, DebugTypeError> {
+        let (kind, location, offset) = self.payload();
+        self.info
+            .enumeration_from_item(kind)
+            .map(|enumeration| DebugEnumeration {
+                unit: self.unit,
+                info: self.info,
+                location,
+                offset,
+                enumeration,
+                path: self.path.clone(),
             })
+            .ok_or_else(|| self.find_alternatives(kind, "enumeration"))
     }
 }
 
@@ -1261,12 +3228,12 @@ impl<'a> DebugEnumeration<'a> {
         unit: &'a unit_info::UnitInfo,
         info: &'a DebugInfo,
         enumeration: &'a unit_info::Enumeration,
-        location: unit_info::MemoryLocation,
+        location: Option<unit_info::MemoryLocation>,
     ) -> Self {
         Self {
             unit,
             info,
-            location: Some(location),
+            location,
             offset: unit_info::StructOffset::new(0),
             enumeration,
             path: String::new(),
@@ -1280,22 +3247,51 @@ impl<'a> DebugEnumeration<'a> {
             .ok_or_else(|| DebugTypeError::BaseTypeNotFound {
                 owner: self.enumeration.name().to_owned(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })?;
         Ok(discriminant.size())
     }
 
+    pub fn generics(&self) -> Vec<DebugGenericParameter<'a>> {
+        self.enumeration
+            .generics()
+            .iter()
+            .map(|generic_parameter| DebugGenericParameter {
+                info: self.info,
+                generic_parameter,
+                path: self.path.clone(),
+            })
+            .collect()
+    }
+
+    /// Return the generic parameter with the given name, such as `T`.
+    pub fn generic_named(&self, name: &str) -> Result<DebugGenericParameter<'a>, DebugTypeError> {
+        self.enumeration
+            .generic_named(name)
+            .map(|generic_parameter| DebugGenericParameter {
+                info: self.info,
+                generic_parameter,
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| DebugTypeError::GenericNotFound {
+                owner: self.enumeration.name().to_owned(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            })
+    }
+
     /// Look through the list of variants and get the variant with the
     /// discriminant that matches the given index.
     pub fn variant_with_discriminant(
         &self,
-        discriminant: usize,
+        discriminant: i128,
     ) -> Result<DebugEnumerationVariant<'a>, DebugTypeError> {
         self.enumeration
             .variant_with_discriminant(discriminant)
             .map(|variant| DebugEnumerationVariant {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + variant.offset()),
+                location: self.location.and_then(|l| l.checked_add(variant.offset())),
                 offset: self.offset + variant.offset(),
                 variant,
                 parent_name: self.enumeration.name().to_owned(),
@@ -1311,6 +3307,7 @@ impl<'a> DebugEnumeration<'a> {
                     .map(|variant| variant.name().to_owned())
                     .collect(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })
     }
 
@@ -1321,7 +3318,7 @@ impl<'a> DebugEnumeration<'a> {
             .map(|variant| DebugEnumerationVariant {
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + variant.offset()),
+                location: self.location.and_then(|l| l.checked_add(variant.offset())),
                 offset: self.offset + variant.offset(),
                 variant,
                 parent_name: self.enumeration.name().to_owned(),
@@ -1337,6 +3334,7 @@ impl<'a> DebugEnumeration<'a> {
                     .map(|variant| variant.name().to_owned())
                     .collect(),
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })
     }
 
@@ -1346,6 +3344,13 @@ impl<'a> DebugEnumeration<'a> {
             .map(|location| location.0)
     }
 
+    /// Place this enumeration at a different address so that another value of the same
+    /// type can be read.
+    pub fn with_address(mut self, address: u64) -> Self {
+        self.location = Some(unit_info::MemoryLocation(address));
+        self
+    }
+
     pub fn variants(&self) -> Result<Vec<DebugEnumerationVariant<'a>>, DebugTypeError> {
         let mut variants = vec![];
         for variant in self.enumeration.variants() {
@@ -1353,7 +3358,7 @@ impl<'a> DebugEnumeration<'a> {
                 parent_name: self.name().to_owned(),
                 unit: self.unit,
                 info: self.info,
-                location: self.location.map(|l| l + variant.offset()),
+                location: self.location.and_then(|l| l.checked_add(variant.offset())),
                 offset: self.offset + variant.offset(),
                 variant,
                 path: make_path_name(&self.path, variant.name()),
@@ -1375,26 +3380,68 @@ impl<'a> DebugEnumeration<'a> {
                 owner: self.enumeration.name().to_owned(),
                 member: None,
                 path: self.path.clone(),
+                location: self.location.map(|location| location.0),
             })?;
-        let discriminant: u64 = match discriminant_size.0 {
-            1 => memory_source
-                .read_u8(address)
-                .map_err(|_| DebugTypeError::ReadError)?
-                .into(),
-            2 => memory_source
-                .read_u16(address)
-                .map_err(|_| DebugTypeError::ReadError)?
-                .into(),
-            4 => memory_source
-                .read_u32(address)
-                .map_err(|_| DebugTypeError::ReadError)?
-                .into(),
-            8 => memory_source
-                .read_u64(address)
-                .map_err(|_| DebugTypeError::ReadError)?,
-            size => return Err(DebugTypeError::SizeError(size)),
+        let endian = self.info.endian();
+        let discriminant = if discriminant_size.0 == 16 {
+            read_u128(memory_source, address, endian)? as i128
+        } else {
+            read_unsigned(memory_source, address, discriminant_size.0, endian)?.into()
         };
-        self.variant_with_discriminant(discriminant as usize)
+        let signed = self
+            .info
+            .base_type_from_item(self.discriminant_kind())
+            .is_some_and(|base_type| base_type.is_signed());
+        self.variant_with_discriminant(unit_info::normalize_discriminant(
+            discriminant,
+            discriminant_size.0,
+            signed,
+        ))
+    }
+
+    /// Whether this looks like a Rust `Option<T>`: exactly two variants, `None` and
+    /// `Some`, where `Some` wraps a single value.
+    pub fn is_option(&self) -> bool {
+        let variants = self.enumeration.variants();
+        variants.len() == 2
+            && self.enumeration.variant_named("None").is_some()
+            && self
+                .enumeration
+                .variant_named("Some")
+                .and_then(|some| self.info.structure_from_item(some.kind()))
+                .is_some_and(|some| some.members().len() == 1)
+    }
+
+    /// Read an `Option<T>`, returning `None` if the value is `None` or the `Some`
+    /// variant if it holds a value. Use [`DebugEnumerationVariant::structure()`] to
+    /// get at the contained value.
+    pub fn option_some_value<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<Option<DebugEnumerationVariant<'a>>, DebugTypeError> {
+        if !self.is_option() {
+            return Err(DebugTypeError::KindIncorrect {
+                owner: self.enumeration.name().to_owned(),
+                member: None,
+                attempted: "Option".into(),
+                actual: "enumeration".into(),
+                path: self.path.clone(),
+                location: self.location.map(|location| location.0),
+            });
+        }
+        let variant = self.variant(memory_source)?;
+        Ok((variant.name() == "Some").then_some(variant))
+    }
+
+    /// Read an `Option<T>` and return the value inside `Some`, or `None` if the value is
+    /// `None`. Niche-optimized layouts such as `Option<&T>` are handled as well.
+    pub fn as_option<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<Option<DebugStructureMember<'a>>, DebugTypeError> {
+        self.option_some_value(memory_source)?
+            .map(|some| some.field(0))
+            .transpose()
     }
 }
 
@@ -1443,7 +3490,7 @@ impl<'a> DebugVariable<'a> {
             .map(|structure| DebugStructure {
                 unit: self.unit,
                 info: self.info,
-                location: Some(self.variable.location()),
+                location: self.variable.location(),
                 offset: unit_info::StructOffset::new(0),
                 structure,
                 path: self.path.clone(),
@@ -1451,6 +3498,7 @@ impl<'a> DebugVariable<'a> {
             .ok_or_else(|| DebugTypeError::StructureNotFound {
                 owner: self.variable.name().to_string(),
                 path: self.path.clone(),
+                location: self.variable.location().map(|location| location.0),
             })
     }
 
@@ -1460,7 +3508,7 @@ impl<'a> DebugVariable<'a> {
             .map(|enumeration| DebugEnumeration {
                 unit: self.unit,
                 info: self.info,
-                location: Some(self.variable.location()),
+                location: self.variable.location(),
                 offset: unit_info::StructOffset::new(0),
                 enumeration,
                 path: self.path.clone(),
@@ -1468,6 +3516,7 @@ impl<'a> DebugVariable<'a> {
             .ok_or_else(|| DebugTypeError::EnumerationNotFound {
                 owner: self.variable.name().to_string(),
                 path: self.path.clone(),
+                location: self.variable.location().map(|location| location.0),
             })
     }
 
@@ -1477,7 +3526,7 @@ impl<'a> DebugVariable<'a> {
             .map(|array| DebugArray {
                 unit: self.unit,
                 info: self.info,
-                location: Some(self.variable.location()),
+                location: self.variable.location(),
                 offset: unit_info::StructOffset::new(0),
                 array,
                 parent_name: self.variable.name().to_string(),
@@ -1486,22 +3535,119 @@ impl<'a> DebugVariable<'a> {
             .ok_or(DebugTypeError::ArrayNotFound {
                 value: self.variable.name().into(),
                 path: self.path.clone(),
+                location: self.variable.location().map(|location| location.0),
             })
     }
 
     pub fn base_type(&self) -> Result<DebugBaseType, DebugTypeError> {
         self.info
             .base_type_from_item(self.variable.kind())
-            .map(|base_type| DebugBaseType {
-                location: Some(self.variable.location()),
-                size: base_type.size(),
-                name: base_type.name().to_owned(),
+            .map(|base_type| {
+                DebugBaseType::from_base_type(
+                    self.variable.location(),
+                    base_type,
+                    self.info.endian(),
+                )
             })
             .ok_or(DebugTypeError::BaseTypeNotFound {
                 path: self.path.clone(),
                 owner: self.variable.name().to_string(),
+                location: self.variable.location().map(|location| location.0),
+            })
+    }
+
+    pub fn union(&self) -> Result<DebugUnion<'a>, DebugTypeError> {
+        self.info
+            .union_from_item(self.variable.kind())
+            .map(|union| DebugUnion {
+                unit: self.unit,
+                info: self.info,
+                location: self.variable.location(),
+                offset: unit_info::StructOffset::new(0),
+                union,
+                path: self.path.clone(),
+            })
+            .ok_or_else(|| DebugTypeError::UnionNotFound {
+                owner: self.variable.name().to_string(),
+                path: self.path.clone(),
+                location: self.variable.location().map(|location| location.0),
             })
     }
+
+    /// Locate the elements of a `&[T]` or `&str` static.
+    pub fn slice<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<DebugSlice<'a>, DebugTypeError> {
+        self.structure()?.as_slice(memory_source)
+    }
+
+    /// Read the whole value of this variable, descending into structures, enumerations
+    /// and arrays, and following pointers if `options` allow it.
+    pub fn read_value<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+        options: ValueOptions,
+    ) -> Result<Value, DebugTypeError> {
+        let item = DebugArrayItem {
+            unit: self.unit,
+            info: self.info,
+            location: self.variable.location(),
+            offset: unit_info::StructOffset::new(0),
+            kind: self.variable.kind(),
+            parent_name: self.variable.name().to_string(),
+            path: self.path.clone(),
+        };
+        crate::value::read_value(&item, memory_source, options)
+    }
+
+    /// Render the value of this variable the way a debugger would print it, such as
+    /// `Config { baud: 115200, mode: Mode::Fast, name: "uart0" }`. Pointers are shown
+    /// as addresses, long arrays are cut short, and memory that can't be read is shown
+    /// as `<unreadable>`. Use [`DebugVariable::read_value()`] for more control.
+    pub fn format<S: Read + ?Sized>(
+        &self,
+        memory_source: &mut S,
+    ) -> Result<String, DebugTypeError> {
+        Ok(self
+            .read_value(memory_source, ValueOptions::default())?
+            .to_string())
+    }
+
+    /// The number of bytes that this variable occupies in memory, or `None` if the
+    /// size of its type isn't known.
+    pub fn size(&self) -> Option<StructOffset> {
+        self.info
+            .byte_size(self.variable.kind())
+            .map(StructOffset::new)
+    }
+
+    /// Find the location and type of the item reached by indexing this variable with
+    /// `indices` and then following the dotted member path `members`. Paths in any
+    /// error are reported relative to `root`.
+    pub(crate) fn resolve_path(
+        mut self,
+        root: &str,
+        indices: &[usize],
+        members: Option<&str>,
+    ) -> Result<(Option<MemoryLocation>, unit_info::DebugItem), DebugTypeError> {
+        self.path = root.to_owned();
+        let Some(members) = members else {
+            if indices.is_empty() {
+                return Ok((self.variable.location(), self.variable.kind()));
+            }
+            let item = self.array()?.get_indexed(indices)?;
+            return Ok((item.location, item.kind));
+        };
+        let container = if !indices.is_empty() {
+            self.array()?.get_indexed(indices)?.member_container()?
+        } else if let Ok(union) = self.union() {
+            MemberContainer::Union(union)
+        } else {
+            MemberContainer::Structure(self.structure()?)
+        };
+        container.walk(members).map(|end| end.resolved())
+    }
 }
 
 impl core::ops::Deref for DebugVariable<'_> {
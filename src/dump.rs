@@ -2,7 +2,7 @@
 
 use crate::GimliReader;
 use gimli::{EndianReader, Endianity, Reader, UnitOffset, UnitSectionOffset};
-use std::rc::Rc;
+use std::sync::Arc;
 
 fn dump_file_index<ENDIAN: Endianity>(
     file_index: u64,
@@ -47,7 +47,7 @@ fn dump_range(range: Option<gimli::Range>) {
 }
 
 fn dump_range_list<ENDIAN: Endianity>(
-    offset: gimli::RangeListsOffset<<EndianReader<ENDIAN, Rc<[u8]>> as Reader>::Offset>,
+    offset: gimli::RangeListsOffset<<EndianReader<ENDIAN, Arc<[u8]>> as Reader>::Offset>,
     unit: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut ranges = unit.ranges(offset)?;
@@ -336,7 +336,7 @@ fn dump_exprloc<ENDIAN: Endianity>(
 }
 
 fn dump_loc_list<ENDIAN: Endianity>(
-    offset: gimli::LocationListsOffset<<EndianReader<ENDIAN, Rc<[u8]>> as Reader>::Offset>,
+    offset: gimli::LocationListsOffset<<EndianReader<ENDIAN, Arc<[u8]>> as Reader>::Offset>,
     unit: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut locations = unit.locations(offset)?;
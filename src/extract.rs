@@ -77,6 +77,12 @@ pub enum VariableLocation {
     Unavailable,
     /// The variable can be found in memory, at this address.
     Address(u64),
+    /// The variable is thread-local, and lives at this offset within the TLS block
+    /// of its module.
+    TlsOffset(u64),
+    /// The location depends on the program counter, and is described by the location
+    /// list at this offset.
+    LocationList(gimli::LocationListsOffset),
     /// The value of the variable is directly available.
     Value,
     /// There was an error evaluating the variable location.
@@ -99,9 +105,11 @@ impl VariableLocation {
     /// Check if the location is valid, ie. not an error, unsupported, or unavailable.
     pub fn valid(&self) -> bool {
         match self {
-            VariableLocation::Address(_) | VariableLocation::Value | VariableLocation::Unknown => {
-                true
-            }
+            VariableLocation::Address(_)
+            | VariableLocation::TlsOffset(_)
+            | VariableLocation::LocationList(_)
+            | VariableLocation::Value
+            | VariableLocation::Unknown => true,
             _other => false,
         }
     }
@@ -113,6 +121,10 @@ impl std::fmt::Display for VariableLocation {
             VariableLocation::Unknown => "<unknown value>".fmt(f),
             VariableLocation::Unavailable => "<value not available>".fmt(f),
             VariableLocation::Address(address) => write!(f, "{address:#010X}"),
+            VariableLocation::TlsOffset(offset) => write!(f, "<TLS offset {offset:#X}>"),
+            VariableLocation::LocationList(offset) => {
+                write!(f, "<location list at {:#X}>", offset.0)
+            }
             VariableLocation::Value => "<not applicable - statically stored value>".fmt(f),
             VariableLocation::Error(error) => error.fmt(f),
             VariableLocation::Unsupported(reason) => reason.fmt(f),
@@ -179,17 +191,28 @@ impl std::fmt::Display for VariableLocation {
 //     None
 // }
 
+type Pieces<ENDIAN> = Vec<gimli::Piece<GimliReader<ENDIAN>, usize>>;
+
 /// Tries to get the result of a DWARF expression in the form of a Piece.
+///
+/// The returned flag is set if the expression asked for the thread-local storage block.
+/// In that case the TLS base is taken to be 0, so any address in the result is an offset
+/// within the TLS block rather than an absolute address.
 pub(crate) fn expression_to_piece<ENDIAN: Endianity>(
     expression: gimli::Expression<GimliReader<ENDIAN>>,
-    encoding: gimli::Encoding,
-) -> Result<Vec<gimli::Piece<GimliReader<ENDIAN>, usize>>, ExtractError> {
-    let mut evaluation = expression.evaluation(encoding);
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> Result<(Pieces<ENDIAN>, bool), ExtractError> {
+    let mut evaluation = expression.evaluation(unit_ref.unit.encoding());
     let mut result = evaluation.evaluate()?;
+    let mut thread_local = false;
 
     loop {
         result = match result {
-            EvaluationResult::Complete => return Ok(evaluation.result()),
+            EvaluationResult::Complete => return Ok((evaluation.result(), thread_local)),
+            EvaluationResult::RequiresTls(offset) => {
+                thread_local = true;
+                evaluation.resume_with_tls(offset)?
+            }
             // EvaluationResult::RequiresMemory { address, size, .. } => {
             //     read_memory(size, memory, address, &mut evaluation)?
             // }
@@ -204,6 +227,11 @@ pub(crate) fn expression_to_piece<ENDIAN: Endianity>(
                 // The address_index as an offset from 0, so just pass it into the next step.
                 evaluation.resume_with_relocated_address(address_index)?
             }
+            EvaluationResult::RequiresIndexedAddress { index, .. } => {
+                // DW_OP_addrx and DW_OP_constx refer to an entry in .debug_addr, relative
+                // to the unit's DW_AT_addr_base.
+                evaluation.resume_with_indexed_address(unit_ref.address(index)?)?
+            }
             // EvaluationResult::RequiresCallFrameCfa => {
             //     provide_cfa(frame_info.canonical_frame_address, &mut evaluation)?
             // }
@@ -225,7 +253,7 @@ pub(crate) fn expression_to_piece<ENDIAN: Endianity>(
 /// - `Result<ExpressionResult::Location(),_>`: One of the variants of VariableLocation, and needs to be interpreted for handling the 'expected' errors we encounter during evaluation.
 pub(crate) fn evaluate_expression<ENDIAN: Endianity>(
     expression: gimli::Expression<GimliReader<ENDIAN>>,
-    encoding: gimli::Encoding,
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Result<ExpressionResult, ExtractError> {
     fn evaluate_address(address: u64) -> ExpressionResult {
         let location = if address >= u32::MAX as u64
@@ -241,7 +269,7 @@ pub(crate) fn evaluate_expression<ENDIAN: Endianity>(
         ExpressionResult::Location(location)
     }
 
-    let pieces = expression_to_piece(expression, encoding)?;
+    let (pieces, thread_local) = expression_to_piece(expression, unit_ref)?;
 
     if pieces.is_empty() {
         return Ok(ExpressionResult::Location(VariableLocation::Error(
@@ -255,6 +283,9 @@ pub(crate) fn evaluate_expression<ENDIAN: Endianity>(
     }
 
     let result = match &pieces[0].location {
+        Location::Address { address } if thread_local => {
+            ExpressionResult::Location(VariableLocation::TlsOffset(*address))
+        }
         Location::Empty => {
             // This means the value was optimized away.
             ExpressionResult::Location(VariableLocation::Unavailable)
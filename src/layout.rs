@@ -0,0 +1,257 @@
+//! `pahole`-style reports of how a type is laid out in memory, as returned by
+//! [DebugInfo::layout_of].
+
+use std::fmt::Write;
+
+use crate::{
+    DebugInfo,
+    unit_info::{DebugItem, Enumeration, Structure, StructureMember, Union},
+};
+
+/// The width of the member column, after which the offset and size are written.
+const MEMBER_COLUMN: usize = 48;
+
+/// Describe the layout of the structure, union or enumeration `item`, expanding nested
+/// aggregates up to `depth` levels deep.
+pub(crate) fn describe(info: &DebugInfo, item: DebugItem, depth: usize) -> Option<String> {
+    let name = info.full_qualified_name_from_item(item)?;
+    let mut report = Report {
+        info,
+        text: String::new(),
+        level: 1,
+    };
+    if let Some(structure) = info.structure_from_item(item) {
+        report.text.push_str(&format!("struct {name} {{\n"));
+        report.members(
+            structure.members(),
+            0,
+            structure.size().unwrap_or(0),
+            0,
+            depth,
+        );
+    } else if let Some(union) = info.union_from_item(item) {
+        report.text.push_str(&format!("union {name} {{\n"));
+        report.union(union, 0, depth);
+    } else {
+        let enumeration = info.enumeration_from_item(item)?;
+        report.text.push_str(&format!("enum {name} {{\n"));
+        report.enumeration(enumeration, 0, depth);
+    }
+    report.text.push_str("};\n");
+    Some(report.text)
+}
+
+/// The name of a member, or its position if it has none.
+fn member_name(member: &StructureMember, index: usize) -> String {
+    member
+        .name()
+        .map(|name| name.to_owned())
+        .unwrap_or_else(|| format!("anonymous[{index}]"))
+}
+
+/// The number of bytes a variant's payload actually uses. Payloads are described as
+/// structures the size of the whole enumeration, so this is the end of the last member.
+fn payload_size(info: &DebugInfo, payload: &Structure) -> u64 {
+    payload
+        .members()
+        .iter()
+        .map(|member| member.offset().0 + info.byte_size(member.kind()).unwrap_or(0))
+        .max()
+        .unwrap_or(0)
+}
+
+struct Report<'a> {
+    info: &'a DebugInfo,
+    text: String,
+    /// How deeply the current line is nested, which sets its indentation.
+    level: usize,
+}
+
+impl Report<'_> {
+    /// Write one line of the report, with the offset and size lined up after the text.
+    fn line(&mut self, text: &str, offset: u64, size: Option<u64>) {
+        let text = format!("{:indent$}{text}", "", indent = 4 * self.level);
+        let size = size.map_or_else(|| "?".to_owned(), |size| size.to_string());
+        let _ = writeln!(
+            self.text,
+            "{text:<width$} /* {offset:>6} {size:>6} */",
+            width = MEMBER_COLUMN
+        );
+    }
+
+    /// Write a line without an offset or size, such as a note about a hole.
+    fn note(&mut self, text: &str) {
+        let _ = writeln!(self.text, "{:indent$}{text}", "", indent = 4 * self.level);
+    }
+
+    /// Write `header` and then whatever `body` writes one level further in, followed by
+    /// a closing brace.
+    fn nested(
+        &mut self,
+        header: &str,
+        offset: u64,
+        size: Option<u64>,
+        body: impl FnOnce(&mut Self),
+    ) {
+        self.line(&format!("{header} {{"), offset, size);
+        self.level += 1;
+        body(self);
+        self.level -= 1;
+        self.note("},");
+    }
+
+    /// Write a member, expanding it if it's a structure, union or enumeration and `depth`
+    /// allows.
+    fn member(&mut self, name: &str, kind: DebugItem, offset: u64, depth: usize) {
+        let info = self.info;
+        let header = format!(
+            "{name}: {}",
+            info.full_qualified_name_from_item(kind)
+                .unwrap_or_else(|| "<unknown>".to_owned())
+        );
+        let size = info.byte_size(kind);
+        if depth > 0
+            && let Some(structure) = info.structure_from_item(kind)
+        {
+            self.nested(&header, offset, size, |report| {
+                report.members(
+                    structure.members(),
+                    0,
+                    structure.size().unwrap_or(0),
+                    offset,
+                    depth - 1,
+                )
+            });
+        } else if depth > 0
+            && let Some(union) = info.union_from_item(kind)
+        {
+            self.nested(&header, offset, size, |report| {
+                report.union(union, offset, depth - 1)
+            });
+        } else if depth > 0
+            && let Some(enumeration) = info.enumeration_from_item(kind)
+        {
+            self.nested(&header, offset, size, |report| {
+                report.enumeration(enumeration, offset, depth - 1)
+            });
+        } else {
+            self.line(&format!("{header},"), offset, size);
+        }
+    }
+
+    /// Write the members of a structure at `base` in order of their offset, noting any
+    /// holes between them and the padding at the end. Holes are counted from `start`,
+    /// which is past the discriminant for the payload of an enumeration variant.
+    fn members(
+        &mut self,
+        members: &[StructureMember],
+        start: u64,
+        size: u64,
+        base: u64,
+        depth: usize,
+    ) {
+        let mut members: Vec<_> = members.iter().enumerate().collect();
+        members.sort_by_key(|(_, member)| member.offset().0);
+        let mut end = start;
+        let mut holes = 0;
+        let mut hole_bytes = 0;
+        for (index, member) in &members {
+            let offset = member.offset().0;
+            if offset > end {
+                self.note(&format!("/* XXX {} byte hole */", offset - end));
+                holes += 1;
+                hole_bytes += offset - end;
+            }
+            self.member(
+                &member_name(member, *index),
+                member.kind(),
+                base + offset,
+                depth,
+            );
+            end = end.max(offset + self.info.byte_size(member.kind()).unwrap_or(0));
+        }
+        let padding = size.saturating_sub(end);
+        if padding > 0 {
+            self.note(&format!("/* XXX {padding} bytes of padding */"));
+        }
+        self.note(&format!(
+            "/* size: {size}, members: {}, holes: {holes}, sum holes: {hole_bytes}, padding: {padding} */",
+            members.len()
+        ));
+    }
+
+    /// Write the members of a union at `base`, which all start at the beginning.
+    fn union(&mut self, union: &Union, base: u64, depth: usize) {
+        let mut largest = 0;
+        for (index, member) in union.members().iter().enumerate() {
+            self.member(&member_name(member, index), member.kind(), base, depth);
+            largest = largest.max(self.info.byte_size(member.kind()).unwrap_or(0));
+        }
+        self.note(&format!(
+            "/* size: {}, members: {}, padding: {} */",
+            union.size(),
+            union.member_count(),
+            union.size().saturating_sub(largest)
+        ));
+    }
+
+    /// Write the discriminant and each variant of an enumeration at `base`, along with
+    /// the largest variant, which decides how big the enumeration is. Variant payloads
+    /// are expanded if `depth` allows.
+    fn enumeration(&mut self, enumeration: &Enumeration, base: u64, depth: usize) {
+        let info = self.info;
+        let discriminant = enumeration.discriminant_kind();
+        let discriminant_size = info.byte_size(discriminant);
+        self.line(
+            &format!(
+                "discriminant: {},",
+                info.full_qualified_name_from_item(discriminant)
+                    .unwrap_or_else(|| "<unknown>".to_owned())
+            ),
+            base + enumeration.discriminant_offset().0,
+            discriminant_size,
+        );
+        // Payloads that share their first bytes with the discriminant leave room for it.
+        let start = if enumeration.discriminant_offset().0 == 0 {
+            discriminant_size.unwrap_or(0)
+        } else {
+            0
+        };
+        let mut largest: Option<(&str, u64)> = None;
+        for variant in enumeration.variants() {
+            let offset = base + variant.offset().0;
+            // C-style enumerators have no payload, so their type is the discriminant itself.
+            let Some(payload) = info
+                .structure_from_item(variant.kind())
+                .filter(|_| variant.kind() != discriminant)
+            else {
+                let value = variant
+                    .discriminant()
+                    .map_or_else(String::new, |value| format!(" = {value}"));
+                self.note(&format!("{}{value},", variant.name()));
+                continue;
+            };
+            let size = payload_size(info, payload);
+            if depth > 0 && !payload.members().is_empty() {
+                self.nested(variant.name(), offset, Some(size), |report| {
+                    report.members(payload.members(), start, size, offset, depth - 1)
+                });
+            } else {
+                self.line(&format!("{},", variant.name()), offset, Some(size));
+            }
+            if largest.is_none_or(|(_, largest)| size > largest) {
+                largest = Some((variant.name(), size));
+            }
+        }
+        let mut summary = format!(
+            "/* size: {}, variants: {}",
+            enumeration.size(),
+            enumeration.variant_count()
+        );
+        if let Some((name, size)) = largest {
+            summary.push_str(&format!(", largest variant: {name} ({size} bytes)"));
+        }
+        summary.push_str(" */");
+        self.note(&summary);
+    }
+}
@@ -37,25 +37,33 @@
 //! ```
 //!
 //! Most of the functionality in this crate comes from [`DebugInfo`].
+mod codegen;
 pub mod debug_types;
 mod dump;
 pub mod extract;
+mod layout;
 pub mod memory;
+pub mod report;
 pub mod unit_info;
+pub mod value;
 
-use gimli::{BigEndian, Endianity, LittleEndian, read::EndianRcSlice};
+use gimli::{BigEndian, Endianity, LittleEndian, Reader, Section, read::EndianArcSlice};
 use object::{Object, ObjectSection};
 use std::borrow;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::Path;
-use std::rc::Rc;
+use std::sync::{Arc, OnceLock};
 
 use debug_types::{DebugBaseType, DebugTypeError, DebugVariable};
 use unit_info::{MemoryLocation, UnitInfo, Variable};
 
 use crate::debug_types::{DebugEnumeration, DebugStructure, DebugUnion};
 
-pub(crate) type GimliReader<ENDIAN> = gimli::EndianReader<ENDIAN, std::rc::Rc<[u8]>>;
+pub(crate) type GimliReader<ENDIAN> = gimli::EndianReader<ENDIAN, std::sync::Arc<[u8]>>;
+
+/// How many typedefs and qualifiers to follow before giving up, since malformed debug
+/// information could contain a cycle.
+const MAX_TYPE_CHAIN: usize = 64;
 
 /// A collection of parsed Dwarf information for all compilation units within
 /// the specified Elf file. This structure can be queried and will automatically
@@ -66,6 +74,249 @@ pub struct DebugInfo {
     /// A mapping from a particular [unit_info::DebugItemOffset](DebugItemOffset) to an address,
     /// useful for resolving a particular debug item to a given unit.
     symbol_unit_mapping: HashMap<unit_info::DebugItem, usize>,
+    /// The byte order of the target described by the Elf file
+    endian: gimli::RunTimeEndian,
+    /// Structures that are only declared in one unit, mapped to their full definition
+    /// in another unit.
+    declarations: HashMap<unit_info::DebugItem, unit_info::DebugItem>,
+    /// The address of each vtable, mapped to the concrete type it was generated for.
+    vtables: HashMap<MemoryLocation, unit_info::DebugItem>,
+    /// The memory occupied by each static variable, sorted by address. This is built the
+    /// first time it's needed.
+    address_index: OnceLock<Vec<AddressRange>>,
+    /// The sections of the Elf file that occupy memory on the target, sorted by address.
+    /// This is empty if the debug information didn't come from an Elf file.
+    sections: Vec<report::Section>,
+    /// The length of the Dwarf information that items were parsed from. Every offset
+    /// that an item could refer to, including ones that weren't parsed, is below this,
+    /// so [DebugInfo::extend] moves new items past it.
+    debug_info_size: u64,
+    /// A checksum of the Elf file, used to tell whether a saved index is out of date.
+    #[cfg(feature = "index")]
+    checksum: u64,
+}
+
+/// The memory occupied by a single static variable
+struct AddressRange {
+    start: u64,
+    end: u64,
+    /// The largest `end` of this range and every range before it, so that a search for
+    /// an address can stop once no earlier range reaches it.
+    max_end: u64,
+    unit_index: usize,
+    variable_index: usize,
+}
+
+/// Controls which optional parts of the debug information are loaded by
+/// [DebugInfo::new_with_options]. Everything is loaded by default. Turning parts off
+/// reduces the memory needed to load large Elf files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugInfoOptions {
+    /// Build the table that maps source lines to addresses, which is used by
+    /// [DebugInfo::source_location_to_addresses]. The header of each line program is
+    /// still read, since it holds the file names used for declarations.
+    pub load_line_info: bool,
+    /// Load `.debug_loc` and `.debug_loclists`. Without them, variables that are
+    /// described by a location list will have no address.
+    pub load_location_lists: bool,
+    /// Load `.debug_ranges` and `.debug_rnglists`.
+    pub load_range_lists: bool,
+}
+
+impl Default for DebugInfoOptions {
+    fn default() -> Self {
+        Self {
+            load_line_info: true,
+            load_location_lists: true,
+            load_range_lists: true,
+        }
+    }
+}
+
+/// A snapshot of all of the type information in a [DebugInfo], as returned by
+/// [DebugInfo::serialized]. Types refer to each other by their offset within the debug
+/// information, which is the key used in each of the maps.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+pub struct SerializedDebugInfo<'a> {
+    /// Incremented whenever the layout of the serialized data changes, so that cached
+    /// copies from an older version can be discarded.
+    pub format_version: u32,
+    /// Either `"little"` or `"big"`.
+    pub endian: &'static str,
+    pub units: Vec<SerializedUnit<'a>>,
+}
+
+/// The layout of the file written by [DebugInfo::save_index].
+#[cfg(feature = "index")]
+#[derive(serde::Serialize)]
+struct IndexRef<'a> {
+    format_version: u32,
+    checksum: u64,
+    endian: &'static str,
+    debug_info_size: u64,
+    units: &'a [UnitInfo],
+    sections: &'a [report::Section],
+}
+
+/// The layout of the file read by [DebugInfo::load_index_for].
+#[cfg(feature = "index")]
+#[derive(serde::Deserialize)]
+struct Index {
+    /// Already checked by reading the [IndexHeader].
+    _format_version: u32,
+    checksum: u64,
+    endian: String,
+    debug_info_size: u64,
+    units: Vec<UnitInfo>,
+    sections: Vec<report::Section>,
+}
+
+/// The start of an index, which is checked before the rest of it is parsed.
+#[cfg(feature = "index")]
+#[derive(serde::Deserialize)]
+struct IndexHeader {
+    format_version: u32,
+    checksum: u64,
+}
+
+/// Incremented whenever the layout of a saved index changes.
+#[cfg(feature = "index")]
+const INDEX_FORMAT_VERSION: u32 = 5;
+
+/// A 64-bit FNV-1a style hash of `data`, taken eight bytes at a time since Elf files
+/// can be large. This only needs to notice when a file has changed, and unlike
+/// `DefaultHasher` it gives the same result with every version of Rust. Each step is
+/// reversible, so changing any one word of `data` always changes the result.
+#[cfg(feature = "index")]
+fn checksum(seed: u64, data: &[u8]) -> u64 {
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut words = data.chunks_exact(8);
+    let hash = words.by_ref().fold(seed, |hash, word| {
+        (hash ^ u64::from_le_bytes(word.try_into().unwrap())).wrapping_mul(PRIME)
+    });
+    words.remainder().iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(feature = "index")]
+const CHECKSUM_SEED: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The types and variables from a single compilation unit, keyed by their offset within
+/// the debug information.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+pub struct SerializedUnit<'a> {
+    pub variables: std::collections::BTreeMap<u64, &'a Variable>,
+    pub structures: std::collections::BTreeMap<u64, &'a unit_info::Structure>,
+    pub enumerations: std::collections::BTreeMap<u64, &'a unit_info::Enumeration>,
+    pub unions: std::collections::BTreeMap<u64, &'a unit_info::Union>,
+    pub arrays: std::collections::BTreeMap<u64, &'a unit_info::Array>,
+    pub pointers: std::collections::BTreeMap<u64, &'a unit_info::Pointer>,
+    pub base_types: std::collections::BTreeMap<u64, &'a unit_info::BaseType>,
+}
+
+/// The number of items of each kind that were found in the debug information, as
+/// returned by [DebugInfo::statistics].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DebugInfoStatistics {
+    pub units: usize,
+    pub variables: usize,
+    pub structures: usize,
+    pub enumerations: usize,
+    pub unions: usize,
+    pub arrays: usize,
+    pub pointers: usize,
+    pub base_types: usize,
+    pub subroutine_types: usize,
+}
+
+impl core::ops::AddAssign for DebugInfoStatistics {
+    fn add_assign(&mut self, other: Self) {
+        self.units += other.units;
+        self.variables += other.variables;
+        self.structures += other.structures;
+        self.enumerations += other.enumerations;
+        self.unions += other.unions;
+        self.arrays += other.arrays;
+        self.pointers += other.pointers;
+        self.base_types += other.base_types;
+        self.subroutine_types += other.subroutine_types;
+    }
+}
+
+/// A type found while iterating over [DebugInfo::structures], [DebugInfo::enumerations]
+/// or [DebugInfo::unions], along with the unit it was found in.
+pub struct DebugTypeEntry<'a, T> {
+    info: &'a DebugInfo,
+    unit_index: usize,
+    item: unit_info::DebugItem,
+    value: &'a T,
+}
+
+impl<'a, T> DebugTypeEntry<'a, T> {
+    /// The index of the unit that this type was found in.
+    pub fn unit_index(&self) -> usize {
+        self.unit_index
+    }
+
+    /// The item of this type, for use with the `*_from_item` functions.
+    pub fn item(&self) -> unit_info::DebugItem {
+        self.item
+    }
+
+    pub fn value(&self) -> &'a T {
+        self.value
+    }
+
+    fn unit(&self) -> &'a UnitInfo {
+        &self.info.units[self.unit_index]
+    }
+}
+
+impl<'a> DebugTypeEntry<'a, unit_info::Structure> {
+    /// Place this structure at `address` so that its members can be read.
+    pub fn at_address(&self, address: u64) -> DebugStructure<'a> {
+        DebugStructure::new(
+            self.unit(),
+            self.info,
+            self.value,
+            Some(MemoryLocation(address)),
+        )
+    }
+}
+
+impl<'a> DebugTypeEntry<'a, unit_info::Enumeration> {
+    /// Place this enumeration at `address` so that its variant can be read.
+    pub fn at_address(&self, address: u64) -> DebugEnumeration<'a> {
+        DebugEnumeration::new(
+            self.unit(),
+            self.info,
+            self.value,
+            Some(MemoryLocation(address)),
+        )
+    }
+}
+
+impl<'a> DebugTypeEntry<'a, unit_info::Union> {
+    /// Place this union at `address` so that its members can be read.
+    pub fn at_address(&self, address: u64) -> DebugUnion<'a> {
+        DebugUnion::new(
+            self.unit(),
+            self.info,
+            self.value,
+            Some(MemoryLocation(address)),
+        )
+    }
+}
+
+impl<T> core::ops::Deref for DebugTypeEntry<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
 }
 
 #[derive(Debug)]
@@ -78,6 +329,22 @@ pub enum DebugInfoError {
     GimliError(gimli::Error),
     /// The requested variable could not be found
     VariableNotFound(String),
+    /// A debug section was present but could not be decompressed
+    SectionDecompression {
+        section: String,
+        error: object::Error,
+    },
+    /// Two Elf files with different byte orders cannot be merged
+    EndianMismatch,
+    /// A saved index could not be read or written
+    #[cfg(feature = "index")]
+    IndexFormat(bincode::Error),
+    /// A saved index was written by a different version of this crate
+    #[cfg(feature = "index")]
+    IndexVersion { expected: u32, found: u32 },
+    /// A saved index was created from a different Elf file
+    #[cfg(feature = "index")]
+    IndexStale,
 }
 
 impl From<object::Error> for DebugInfoError {
@@ -92,6 +359,13 @@ impl From<std::io::Error> for DebugInfoError {
     }
 }
 
+#[cfg(feature = "index")]
+impl From<bincode::Error> for DebugInfoError {
+    fn from(value: bincode::Error) -> Self {
+        DebugInfoError::IndexFormat(value)
+    }
+}
+
 impl From<gimli::Error> for DebugInfoError {
     fn from(value: gimli::Error) -> Self {
         DebugInfoError::GimliError(value)
@@ -105,12 +379,65 @@ impl core::fmt::Display for DebugInfoError {
             DebugInfoError::IoError(error) => write!(f, "IO Error: {}", error),
             DebugInfoError::GimliError(error) => write!(f, "Gimli Error: {}", error),
             DebugInfoError::VariableNotFound(error) => write!(f, "Variable {} not found", error),
+            DebugInfoError::SectionDecompression { section, error } => {
+                write!(
+                    f,
+                    "Section {} could not be decompressed: {}",
+                    section, error
+                )
+            }
+            DebugInfoError::EndianMismatch => {
+                write!(f, "Elf files have different byte orders")
+            }
+            #[cfg(feature = "index")]
+            DebugInfoError::IndexFormat(error) => write!(f, "Index Error: {}", error),
+            #[cfg(feature = "index")]
+            DebugInfoError::IndexVersion { expected, found } => {
+                write!(f, "Index is version {}, expected {}", found, expected)
+            }
+            #[cfg(feature = "index")]
+            DebugInfoError::IndexStale => {
+                write!(f, "Index was created from a different Elf file")
+            }
         }
     }
 }
 
 impl std::error::Error for DebugInfoError {}
 
+/// Match `text` against a glob `pattern`, where `*` matches any run of characters
+/// (including none) and `?` matches exactly one character.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // The position of the most recent `*` in the pattern, and the text position that it
+    // was tried against. If a later character fails to match, the star absorbs one more
+    // character of text and matching resumes from there.
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => {
+                let Some((star, star_t)) = backtrack else {
+                    return false;
+                };
+                p = star + 1;
+                t = star_t + 1;
+                backtrack = Some((star, star_t + 1));
+            }
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 pub(crate) fn split_namespace_and_name(kind: &str) -> (&str, &str) {
     // If the kind is a reference, mut reference, or anything else that's not a normal struct, do
     // not attempt to split out the namespace.
@@ -145,30 +472,223 @@ impl DebugInfo {
     /// This will parse the file and extract each unit section, then perform a comprehensive parse
     /// of all symbols present within the file.
     pub fn new<P: AsRef<Path>>(file: &P) -> Result<DebugInfo, DebugInfoError> {
+        Self::new_with_options(file, DebugInfoOptions::default())
+    }
+
+    /// Create a new [DebugInfo] object from the Elf file at the specified path, loading
+    /// only the parts of the debug information that are enabled in `options`.
+    pub fn new_with_options<P: AsRef<Path>>(
+        file: &P,
+        options: DebugInfoOptions,
+    ) -> Result<DebugInfo, DebugInfoError> {
         let file = std::fs::read(file)?;
-        let object = object::File::parse(file.as_slice())?;
+        Self::from_bytes_with_options(&file, options)
+    }
 
-        if object.is_little_endian() {
-            Self::load::<LittleEndian>(object, LittleEndian)
+    /// Create a new [DebugInfo] object by reading an entire Elf file from `reader`. This is
+    /// useful when the file is not available on disk, for example when it is received
+    /// over a serial port or a network socket.
+    pub fn from_io_reader<R: std::io::Read>(mut reader: R) -> Result<DebugInfo, DebugInfoError> {
+        let mut file = vec![];
+        reader.read_to_end(&mut file)?;
+        Self::from_bytes(&file)
+    }
+
+    /// Create a new [DebugInfo] object from the contents of an Elf file that is already
+    /// in memory.
+    pub fn from_bytes(data: &[u8]) -> Result<DebugInfo, DebugInfoError> {
+        Self::from_bytes_with_options(data, DebugInfoOptions::default())
+    }
+
+    /// Create a new [DebugInfo] object from the contents of an Elf file that is already
+    /// in memory, loading only the parts of the debug information that are enabled in
+    /// `options`.
+    pub fn from_bytes_with_options(
+        data: &[u8],
+        options: DebugInfoOptions,
+    ) -> Result<DebugInfo, DebugInfoError> {
+        let object = object::File::parse(data)?;
+
+        // Sections may also be stored compressed under the GNU `.zdebug_*` name.
+        let section_data = |id: gimli::SectionId| {
+            let section = object.section_by_name(id.name()).or_else(|| {
+                id.name()
+                    .strip_prefix(".debug_")
+                    .and_then(|name| object.section_by_name(&format!(".zdebug_{name}")))
+            });
+            match section {
+                Some(section) => section.uncompressed_data().map_err(|error| {
+                    DebugInfoError::SectionDecompression {
+                        section: id.name().to_owned(),
+                        error,
+                    }
+                }),
+                None => Ok(borrow::Cow::Borrowed(&[][..])),
+            }
+        };
+        let debug_info = if object.is_little_endian() {
+            Self::load(section_data, LittleEndian, options)
         } else {
-            Self::load::<BigEndian>(object, BigEndian)
+            Self::load(section_data, BigEndian, options)
+        };
+        let mut debug_info = debug_info?;
+        #[cfg(feature = "index")]
+        {
+            debug_info.checksum = checksum(CHECKSUM_SEED, data);
         }
+        // Only sections with `SHF_ALLOC` are loaded onto the target, which may be at
+        // address 0, such as a vector table. `.tbss` only describes memory that each
+        // thread allocates for itself.
+        debug_info.sections = object
+            .sections()
+            .filter(|section| {
+                matches!(
+                    section.flags(),
+                    object::SectionFlags::Elf { sh_flags }
+                        if sh_flags & u64::from(object::elf::SHF_ALLOC) != 0
+                ) && section.size() != 0
+                    && section.kind() != object::SectionKind::UninitializedTls
+            })
+            .filter_map(|section| {
+                Some(report::Section {
+                    name: section.name().ok()?.to_owned(),
+                    address: section.address(),
+                    size: section.size(),
+                })
+            })
+            .collect();
+        debug_info.sections.sort_by_key(|section| section.address);
+        Ok(debug_info)
     }
 
-    fn load<ENDIAN: Endianity>(
-        object: object::File<'_>,
-        endian: ENDIAN,
+    /// Write everything that was parsed from the Elf file to `path`, so that it can be
+    /// loaded again with [DebugInfo::load_index_for] without parsing the Elf file.
+    ///
+    /// The index is written with `bincode`, so loading it is little more than copying
+    /// the types back into memory. It starts with a format version and a checksum of
+    /// the Elf file, which are checked before anything else is read.
+    #[cfg(feature = "index")]
+    pub fn save_index<P: AsRef<Path>>(&self, path: P) -> Result<(), DebugInfoError> {
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        bincode::serialize_into(
+            file,
+            &IndexRef {
+                format_version: INDEX_FORMAT_VERSION,
+                checksum: self.checksum,
+                endian: if self.endian.is_big_endian() {
+                    "big"
+                } else {
+                    "little"
+                },
+                debug_info_size: self.debug_info_size,
+                units: &self.units,
+                sections: &self.sections,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Load an index that was written by [DebugInfo::save_index] without checking
+    /// which Elf file it was created from, so an index for a file that has since been
+    /// rebuilt is loaded without complaint.
+    #[cfg(feature = "index")]
+    #[deprecated(note = "use `load_index_for`, which notices when the Elf file has changed")]
+    pub fn load_index<P: AsRef<Path>>(path: P) -> Result<DebugInfo, DebugInfoError> {
+        Self::read_index(path, None)
+    }
+
+    /// Load an index that was written by [DebugInfo::save_index], and make sure that it
+    /// was created from the Elf file whose contents are `elf`. Returns
+    /// [DebugInfoError::IndexStale] if it wasn't, in which case the Elf file should be
+    /// parsed again.
+    #[cfg(feature = "index")]
+    pub fn load_index_for<P: AsRef<Path>>(
+        path: P,
+        elf: &[u8],
     ) -> Result<DebugInfo, DebugInfoError> {
-        let mut symbol_unit_mapping = HashMap::new();
-        // Load a section and return as `Cow<[u8]>`.
-        let load_section = |id: gimli::SectionId| -> Result<EndianRcSlice<ENDIAN>, gimli::Error> {
-            let data = object
-                .section_by_name(id.name())
-                .and_then(|section| section.uncompressed_data().ok())
-                .unwrap_or_else(|| borrow::Cow::Borrowed(&[][..]));
-
-            Ok(EndianRcSlice::new(Rc::from(&*data), endian))
+        Self::read_index(path, Some(checksum(CHECKSUM_SEED, elf)))
+    }
+
+    /// Read the index at `path`, which must have been created from an Elf file with
+    /// the checksum `expected_checksum` if one is given.
+    #[cfg(feature = "index")]
+    fn read_index<P: AsRef<Path>>(
+        path: P,
+        expected_checksum: Option<u64>,
+    ) -> Result<DebugInfo, DebugInfoError> {
+        let data = std::fs::read(path)?;
+        let header: IndexHeader = bincode::deserialize(&data)?;
+        if header.format_version != INDEX_FORMAT_VERSION {
+            return Err(DebugInfoError::IndexVersion {
+                expected: INDEX_FORMAT_VERSION,
+                found: header.format_version,
+            });
+        }
+        if expected_checksum.is_some_and(|expected| expected != header.checksum) {
+            return Err(DebugInfoError::IndexStale);
+        }
+        let index: Index = bincode::deserialize(&data)?;
+        let endian = if index.endian == "big" {
+            gimli::RunTimeEndian::Big
+        } else {
+            gimli::RunTimeEndian::Little
         };
+        let mut debug_info = Self::from_units(index.units, endian);
+        debug_info.debug_info_size = index.debug_info_size;
+        debug_info.checksum = index.checksum;
+        debug_info.sections = index.sections;
+        Ok(debug_info)
+    }
+
+    /// Create a new [DebugInfo] object from Dwarf sections that have already been
+    /// extracted, rather than from an Elf file. `sections` maps section names such as
+    /// `.debug_info` and `.debug_abbrev` to their uncompressed contents. Sections that
+    /// are missing are treated as though they were empty.
+    ///
+    /// This is useful when the sections come from somewhere other than an Elf file, or
+    /// need to be patched or decompressed before they are parsed.
+    pub fn from_section_map(
+        sections: HashMap<&str, Vec<u8>>,
+        is_little_endian: bool,
+    ) -> Result<DebugInfo, DebugInfoError> {
+        let section_data = |id: gimli::SectionId| {
+            Ok(borrow::Cow::Borrowed(
+                sections.get(id.name()).map_or(&[][..], |data| &data[..]),
+            ))
+        };
+        let options = DebugInfoOptions::default();
+        if is_little_endian {
+            Self::load(section_data, LittleEndian, options)
+        } else {
+            Self::load(section_data, BigEndian, options)
+        }
+    }
+
+    /// Parse the Dwarf sections returned by `section_data`.
+    fn load<'data, ENDIAN: Endianity>(
+        section_data: impl Fn(gimli::SectionId) -> Result<borrow::Cow<'data, [u8]>, DebugInfoError>,
+        endian: ENDIAN,
+        options: DebugInfoOptions,
+    ) -> Result<DebugInfo, DebugInfoError> {
+        // Load a section and return as `Cow<[u8]>`. Sections that were turned off in
+        // `options` are treated as though they were empty.
+        let load_section =
+            |id: gimli::SectionId| -> Result<EndianArcSlice<ENDIAN>, DebugInfoError> {
+                let wanted = match id {
+                    gimli::SectionId::DebugLoc | gimli::SectionId::DebugLocLists => {
+                        options.load_location_lists
+                    }
+                    gimli::SectionId::DebugRanges | gimli::SectionId::DebugRngLists => {
+                        options.load_range_lists
+                    }
+                    _ => true,
+                };
+                if !wanted {
+                    return Ok(EndianArcSlice::new(Arc::from(&[][..]), endian));
+                }
+                let data = section_data(id)?;
+                Ok(EndianArcSlice::new(Arc::from(&*data), endian))
+            };
 
         // Load all of the sections.
         let dwarf_cow = gimli::Dwarf::load(&load_section)?;
@@ -186,21 +706,520 @@ impl DebugInfo {
                 // The frame section address size is only used for CIE versions before 4.
                 // frame_section.set_address_size(unit.encoding().address_size);
 
-                if let Some(unit) = UnitInfo::new(unit, &dwarf_cow) {
-                    for symbol in unit.all_symbols() {
-                        assert!(symbol_unit_mapping.insert(symbol, units.len()).is_none());
-                    }
+                if let Some(unit) = UnitInfo::new(unit, &dwarf_cow, &options) {
                     units.push(unit);
                 }
             }
         }
 
-        Ok(DebugInfo {
+        let mut debug_info = Self::from_units(
+            units,
+            if endian.is_big_endian() {
+                gimli::RunTimeEndian::Big
+            } else {
+                gimli::RunTimeEndian::Little
+            },
+        );
+        debug_info.debug_info_size =
+            (dwarf_cow.debug_info.reader().len() + dwarf_cow.debug_types.reader().len()) as u64;
+        Ok(debug_info)
+    }
+
+    /// Build the tables that link the units together.
+    fn from_units(units: Vec<UnitInfo>, endian: gimli::RunTimeEndian) -> DebugInfo {
+        let mut symbol_unit_mapping = HashMap::new();
+        for (index, unit) in units.iter().enumerate() {
+            for symbol in unit.all_symbols() {
+                match symbol_unit_mapping.entry(symbol) {
+                    std::collections::hash_map::Entry::Occupied(_) => {
+                        log::warn!("Symbol {symbol:?} appears in more than one unit");
+                    }
+                    std::collections::hash_map::Entry::Vacant(entry) => {
+                        entry.insert(index);
+                    }
+                }
+            }
+        }
+
+        let declarations = Self::link_declarations(&units);
+        let vtables = units.iter().flat_map(|unit| unit.vtables()).collect();
+        let debug_info_size = symbol_unit_mapping
+            .keys()
+            .map(|item| item.offset + 1)
+            .max()
+            .unwrap_or(0);
+
+        DebugInfo {
             units,
             symbol_unit_mapping,
+            declarations,
+            vtables,
+            address_index: OnceLock::new(),
+            sections: vec![],
+            debug_info_size,
+            endian,
+            #[cfg(feature = "index")]
+            checksum: 0,
+        }
+    }
+
+    /// Link structures that are only declared in one unit to a definition with the same
+    /// name in another unit.
+    fn link_declarations(
+        units: &[UnitInfo],
+    ) -> HashMap<unit_info::DebugItem, unit_info::DebugItem> {
+        let mut definitions = HashMap::new();
+        for structure in units.iter().flat_map(|unit| unit.structures()) {
+            if !structure.is_declaration() {
+                definitions
+                    .entry((structure.namespace(), structure.name()))
+                    .or_insert(structure.kind());
+            }
+        }
+        let mut declarations = HashMap::new();
+        for structure in units.iter().flat_map(|unit| unit.structures()) {
+            if structure.is_declaration()
+                && let Some(definition) =
+                    definitions.get(&(structure.namespace(), structure.name()))
+            {
+                declarations.insert(structure.kind(), *definition);
+            }
+        }
+        declarations
+    }
+
+    /// Combine the debug information from two Elf files, such as an application and the
+    /// RTOS it runs on, so that symbols from both can be queried together. See
+    /// [DebugInfo::extend] for how overlapping information is handled.
+    pub fn merge(mut a: DebugInfo, b: DebugInfo) -> Result<DebugInfo, DebugInfoError> {
+        a.extend(b)?;
+        Ok(a)
+    }
+
+    /// Add the debug information from another Elf file to this one.
+    ///
+    /// Items from `other` are moved past the items that are already loaded, so types
+    /// from the two files never collide even though their Dwarf offsets overlap.
+    /// Variables from `other` whose address matches a variable that is already loaded are
+    /// dropped, so the existing information wins. Structures that are only declared in one
+    /// file are linked to their definition in the other.
+    ///
+    /// Both files must have the same byte order.
+    pub fn extend(&mut self, other: DebugInfo) -> Result<(), DebugInfoError> {
+        if self.endian != other.endian {
+            return Err(DebugInfoError::EndianMismatch);
+        }
+
+        // Members and pointers can refer to items that weren't parsed, so the whole of
+        // this file's Dwarf information is skipped rather than just the parsed items.
+        let base = self.debug_info_size;
+        let locations: HashSet<MemoryLocation> = self
+            .units
+            .iter()
+            .flat_map(|unit| unit.variables())
+            .filter_map(|variable| variable.location())
+            .collect();
+
+        for mut unit in other.units {
+            unit.rebase(base);
+            unit.retain_variables(|variable| {
+                variable
+                    .location()
+                    .is_none_or(|location| !locations.contains(&location))
+            });
+            for symbol in unit.all_symbols() {
+                self.symbol_unit_mapping.insert(symbol, self.units.len());
+            }
+            self.units.push(unit);
+        }
+        self.debug_info_size = base + other.debug_info_size;
+        self.declarations = Self::link_declarations(&self.units);
+        self.vtables = self.units.iter().flat_map(|unit| unit.vtables()).collect();
+        self.address_index = OnceLock::new();
+        self.sections.extend(other.sections);
+        self.sections.sort_by_key(|section| section.address);
+        #[cfg(feature = "index")]
+        {
+            self.checksum = checksum(self.checksum, &other.checksum.to_le_bytes());
+        }
+        Ok(())
+    }
+
+    /// If `item` is a structure declaration, return the item of its full definition.
+    fn resolve_declaration(&self, item: unit_info::DebugItem) -> unit_info::DebugItem {
+        self.declarations.get(&item).copied().unwrap_or(item)
+    }
+
+    /// Look through any typedefs and qualifiers such as `const`, and through structure
+    /// declarations, to find the item that actually describes the type.
+    fn resolve_item(&self, item: unit_info::DebugItem) -> unit_info::DebugItem {
+        self.resolve_declaration(self.resolve_type_chain(item))
+    }
+
+    /// Count the items of each kind across all units. This is useful for checking
+    /// whether the information you expect was parsed at all.
+    pub fn statistics(&self) -> DebugInfoStatistics {
+        let mut statistics = DebugInfoStatistics::default();
+        for unit in &self.units {
+            statistics += unit.statistics();
+        }
+        statistics
+    }
+
+    /// The number of static variables across all units.
+    pub fn variable_count(&self) -> usize {
+        self.statistics().variables
+    }
+
+    /// The number of structures across all units. Types that are described by several
+    /// units are counted once per unit.
+    pub fn structure_count(&self) -> usize {
+        self.statistics().structures
+    }
+
+    /// The number of enumerations across all units. Types that are described by several
+    /// units are counted once per unit.
+    pub fn enumeration_count(&self) -> usize {
+        self.statistics().enumerations
+    }
+
+    /// The number of unions across all units. Types that are described by several
+    /// units are counted once per unit.
+    pub fn union_count(&self) -> usize {
+        self.statistics().unions
+    }
+
+    /// The number of base types across all units. Types that are described by several
+    /// units are counted once per unit.
+    pub fn base_type_count(&self) -> usize {
+        self.statistics().base_types
+    }
+
+    /// The number of array types across all units. Types that are described by several
+    /// units are counted once per unit.
+    pub fn array_count(&self) -> usize {
+        self.statistics().arrays
+    }
+
+    /// The number of pointer types across all units. Types that are described by several
+    /// units are counted once per unit.
+    pub fn pointer_count(&self) -> usize {
+        self.statistics().pointers
+    }
+
+    /// The sections of the Elf file that occupy memory on the target, such as `.text`,
+    /// `.data` and `.bss`, sorted by address. This is empty if the debug information
+    /// wasn't loaded from an Elf file.
+    pub fn sections(&self) -> &[report::Section] {
+        &self.sections
+    }
+
+    /// List every static variable that has an address, along with its size, the file
+    /// that declared it, and the section that it's in, sorted by address. Variables that
+    /// several units describe are listed once. Use [report::totals_by] to add the sizes
+    /// up by crate, module or file, and [Self::section_usage] to compare them with the
+    /// sections of the Elf file.
+    pub fn static_size_report(&self) -> Vec<report::ReportRow> {
+        let mut seen = HashSet::new();
+        let mut rows = vec![];
+        for variable in self.units.iter().flat_map(|unit| unit.variables()) {
+            let Some(location) = variable.location() else {
+                continue;
+            };
+            if !seen.insert((location, variable.namespace(), variable.name())) {
+                continue;
+            }
+            let name = variable
+                .name()
+                .strip_prefix(variable.namespace())
+                .and_then(|name| name.strip_prefix("::"))
+                .unwrap_or(variable.name());
+            rows.push(report::ReportRow {
+                name: name.to_owned(),
+                namespace: variable.namespace().to_owned(),
+                file: variable.file().map(|file| file.to_owned()),
+                address: location.0,
+                size: self.size_from_item(variable.kind()).map(|size| size.0),
+                section: self
+                    .sections
+                    .iter()
+                    .find(|section| section.contains(location.0))
+                    .map(|section| section.name.clone()),
+            });
+        }
+        rows.sort_by(|a, b| a.address.cmp(&b.address).then_with(|| a.name.cmp(&b.name)));
+        rows
+    }
+
+    /// Add up the variables in `rows` for each section that contains any of them, as a
+    /// cross-check of the debug information against the Elf file. A section whose usage
+    /// is much smaller than its size holds data that the debug information doesn't
+    /// describe.
+    pub fn section_usage(&self, rows: &[report::ReportRow]) -> Vec<report::SectionUsage> {
+        self.sections
+            .iter()
+            .filter_map(|section| {
+                let mut usage = report::SectionUsage {
+                    section: section.clone(),
+                    variables: 0,
+                    used: 0,
+                };
+                for row in rows.iter().filter(|row| section.contains(row.address)) {
+                    usage.variables += 1;
+                    usage.used += row.size.unwrap_or(0);
+                }
+                (usage.variables > 0).then_some(usage)
+            })
+            .collect()
+    }
+
+    /// Return every address that the code on `line` of a source file was compiled to, for
+    /// example to set a breakpoint. The file is matched if its path ends with `file_suffix`,
+    /// such as `main.rs` or `src/main.rs`. A single line may produce several addresses,
+    /// for example when it is inlined or unrolled. The addresses are sorted.
+    pub fn source_location_to_addresses(&self, file_suffix: &str, line: u64) -> Vec<u64> {
+        let mut addresses: Vec<u64> = self
+            .units
+            .iter()
+            .flat_map(|unit| unit.addresses_for_line(file_suffix, line))
+            .collect();
+        addresses.sort_unstable();
+        addresses.dedup();
+        addresses
+    }
+
+    /// Return the compilation unit whose code covers `address`, such as a program counter
+    /// from a crash report.
+    pub fn unit_for_address(&self, address: u64) -> Option<&UnitInfo> {
+        self.units
+            .iter()
+            .find(|unit| unit.contains_address(address))
+    }
+
+    /// Find the static variable that contains `address`, along with the offset of `address`
+    /// within it. Variables whose size can't be determined are assumed to be one byte long.
+    pub fn variable_at_address(&self, address: u64) -> Option<(DebugVariable<'_>, u64)> {
+        let index = self
+            .address_index
+            .get_or_init(|| self.build_address_index());
+        // Variables may overlap, for example when they are placed in overlays, so the
+        // range that starts closest to the address may end before it while an earlier one
+        // doesn't. The innermost range that contains the address is returned.
+        let range = index[..index.partition_point(|range| range.start <= address)]
+            .iter()
+            .rev()
+            .take_while(|range| range.max_end > address)
+            .find(|range| address < range.end)?;
+        let unit = &self.units[range.unit_index];
+        let variable = &unit.variables()[range.variable_index];
+        Some((
+            DebugVariable::new(unit, self, variable),
+            address - range.start,
+        ))
+    }
+
+    /// Describe `address` as a path into the static variable that contains it, such as
+    /// `HEAP.blocks[3].next`. If the address is not at the start of a member, the remaining
+    /// offset is added, as in `COUNTER+2`. Enumerations are not descended into, since the
+    /// active variant depends on the contents of memory.
+    pub fn describe_address(&self, address: u64) -> Option<String> {
+        let (variable, mut offset) = self.variable_at_address(address)?;
+        let mut path = variable.name().trim_start_matches("::").to_owned();
+        let mut kind = variable.kind();
+        loop {
+            if let Some(structure) = self.structure_from_item(kind) {
+                let Some(member) = structure.members().iter().find(|member| {
+                    let size = self.byte_size(member.kind()).unwrap_or(1);
+                    (member.offset().0..member.offset().0 + size).contains(&offset)
+                }) else {
+                    break;
+                };
+                path.push('.');
+                path.push_str(member.name().unwrap_or("<anonymous>"));
+                offset -= member.offset().0;
+                kind = member.kind();
+            } else if let Some(array) = self.array_from_item(kind)
+                && let Some(element_size) =
+                    array.byte_stride().or_else(|| self.byte_size(array.kind()))
+                && element_size > 0
+            {
+                let index = (offset / element_size) as usize;
+                if index >= array.count() {
+                    break;
+                }
+                path.push_str(&debug_types::array_index_name(array, index));
+                offset %= element_size;
+                kind = array.kind();
+            } else {
+                break;
+            }
+        }
+        if offset != 0 {
+            path.push_str(&format!("+{offset}"));
+        }
+        Some(path)
+    }
+
+    fn build_address_index(&self) -> Vec<AddressRange> {
+        let mut index = vec![];
+        for (unit_index, unit) in self.units.iter().enumerate() {
+            for (variable_index, variable) in unit.variables().iter().enumerate() {
+                let Some(location) = variable.location() else {
+                    continue;
+                };
+                let size = self.byte_size(variable.kind()).unwrap_or(1).max(1);
+                index.push(AddressRange {
+                    start: location.0,
+                    end: location.0.saturating_add(size),
+                    max_end: 0,
+                    unit_index,
+                    variable_index,
+                });
+            }
+        }
+        index.sort_by_key(|range| range.start);
+        let mut max_end = 0;
+        for range in &mut index {
+            max_end = max_end.max(range.end);
+            range.max_end = max_end;
+        }
+        index
+    }
+
+    /// The size of `item` in bytes. Unlike [Self::size_from_item], this can also determine
+    /// the size of arrays and pointers.
+    pub(crate) fn byte_size(&self, item: unit_info::DebugItem) -> Option<u64> {
+        let item = self.resolve_item(item);
+        if let Some(size) = self
+            .symbol_unit_mapping
+            .get(&item)
+            .and_then(|unit| self.units[*unit].size_from_item(item))
+        {
+            return Some(size.0);
+        }
+        if let Some(array) = self.array_from_item(item) {
+            let stride = match array.byte_stride() {
+                Some(stride) => stride,
+                None => self.byte_size(array.kind())?,
+            };
+            return Some(stride * array.count() as u64);
+        }
+        let unit = &self.units[*self.symbol_unit_mapping.get(&item)?];
+        unit.pointer_from_item(item)
+            .map(|_| unit.address_size.into())
+    }
+
+    /// Return the concrete type that the vtable at `address` was generated for. This is
+    /// used to find the real type behind a trait object.
+    pub fn type_from_vtable(&self, address: u64) -> Option<unit_info::DebugItem> {
+        self.vtables.get(&MemoryLocation(address)).copied()
+    }
+
+    /// Collect all of the type information so that it can be serialized, for example to
+    /// JSON to be sent to another process or kept as a build artifact.
+    #[cfg(feature = "serde")]
+    pub fn serialized(&self) -> SerializedDebugInfo<'_> {
+        SerializedDebugInfo {
+            format_version: 1,
+            endian: if self.endian.is_big_endian() {
+                "big"
+            } else {
+                "little"
+            },
+            units: self.units.iter().map(UnitInfo::serialized).collect(),
+        }
+    }
+
+    /// Return the byte order of the target, as declared by the Elf file.
+    pub fn endian(&self) -> gimli::RunTimeEndian {
+        self.endian
+    }
+
+    /// Iterate over every static variable in every unit. Variables are returned in the
+    /// order that they appear in the Elf file.
+    pub fn variables(&self) -> impl Iterator<Item = DebugVariable<'_>> {
+        self.units.iter().flat_map(move |unit| {
+            unit.variables()
+                .iter()
+                .map(move |variable| DebugVariable::new(unit, self, variable))
         })
     }
 
+    /// Iterate over every structure in every unit. A structure that is described by several
+    /// units is only returned once, and declarations without a definition are skipped.
+    pub fn structures(&self) -> impl Iterator<Item = DebugTypeEntry<'_, unit_info::Structure>> {
+        self.type_entries(
+            |unit| {
+                unit.structure_items()
+                    .into_iter()
+                    .filter(|(_, structure)| !structure.is_declaration())
+                    .collect()
+            },
+            |structure| (structure.namespace(), structure.name()),
+        )
+    }
+
+    /// Iterate over every enumeration in every unit. An enumeration that is described by
+    /// several units is only returned once.
+    pub fn enumerations(&self) -> impl Iterator<Item = DebugTypeEntry<'_, unit_info::Enumeration>> {
+        self.type_entries(UnitInfo::enumeration_items, |enumeration| {
+            (enumeration.namespace(), enumeration.name())
+        })
+    }
+
+    /// Iterate over every union in every unit. A union that is described by several units
+    /// is only returned once.
+    pub fn unions(&self) -> impl Iterator<Item = DebugTypeEntry<'_, unit_info::Union>> {
+        self.type_entries(UnitInfo::union_items, |union| {
+            (union.namespace(), union.name())
+        })
+    }
+
+    /// List every namespace that contains a variable, structure, enumeration or union,
+    /// such as `core::option` or `my_crate::drivers`. The list is sorted and contains
+    /// no duplicates. Only namespaces that directly contain an item are listed, so
+    /// `my_crate` appears only if something is declared at its top level.
+    pub fn enumerate_namespaces(&self) -> Vec<String> {
+        let mut namespaces = BTreeSet::new();
+        for unit in &self.units {
+            for (name, _) in unit.variables_matching_demangled_name(|_| true) {
+                namespaces.insert(split_namespace_and_name(name).0);
+            }
+        }
+        namespaces.extend(self.structures().map(|entry| entry.value.namespace()));
+        namespaces.extend(self.enumerations().map(|entry| entry.value.namespace()));
+        namespaces.extend(self.unions().map(|entry| entry.value.namespace()));
+        namespaces
+            .into_iter()
+            .filter(|namespace| !namespace.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Iterate over the types returned by `items` for every unit, skipping any whose
+    /// namespace and name have already been seen.
+    fn type_entries<'a, T>(
+        &'a self,
+        items: fn(&'a UnitInfo) -> Vec<(unit_info::DebugItem, &'a T)>,
+        name: fn(&'a T) -> (&'a str, &'a str),
+    ) -> impl Iterator<Item = DebugTypeEntry<'a, T>> {
+        let mut seen = HashSet::new();
+        self.units
+            .iter()
+            .enumerate()
+            .flat_map(move |(unit_index, unit)| {
+                items(unit)
+                    .into_iter()
+                    .map(move |(item, value)| DebugTypeEntry {
+                        info: self,
+                        unit_index,
+                        item,
+                        value,
+                    })
+            })
+            .filter(move |entry| seen.insert(name(entry.value)))
+    }
+
     /// Consult all units to look for a variant with the specified name. If the variable
     /// cannot be found, return an error. Note that only rustc name mangling is supported.
     pub fn variable_from_demangled_name(
@@ -211,7 +1230,14 @@ impl DebugInfo {
         match results.len() {
             0 => Err(DebugTypeError::VariableNotFound(path.into())),
             1 => Ok(results.pop().unwrap()),
-            _ => Err(DebugTypeError::MultipleMatches),
+            _ => Err(DebugTypeError::MultipleMatches {
+                path: path.into(),
+                candidates: results
+                    .iter()
+                    .map(|variable| variable.name().to_owned())
+                    .collect(),
+                location: None,
+            }),
         }
     }
 
@@ -228,6 +1254,242 @@ impl DebugInfo {
         results
     }
 
+    /// Find the address and type of the item named by `path` without reading target
+    /// memory. The path starts with the demangled name of a static, which may be followed
+    /// by array indices and a dotted member path, such as `mycrate::STATE.inner.count` or
+    /// `mycrate::TABLE[2].flags`.
+    pub fn address_of(&self, path: &str) -> Result<(u64, unit_info::DebugItem), DebugTypeError> {
+        let (root, members) = match path.split_once('.') {
+            Some((root, members)) => (root, Some(members)),
+            None => (path, None),
+        };
+        let (name, indices) = debug_types::split_path_segment(root)
+            .ok_or_else(|| DebugTypeError::VariableNotFound(root.into()))?;
+        let (location, kind) = self
+            .variable_from_demangled_name(name)?
+            .resolve_path(root, &indices, members)?;
+        let location = location.ok_or(DebugTypeError::LocationMissing)?;
+        Ok((location.0, kind))
+    }
+
+    /// Read the base type named by `path`, using the same syntax as
+    /// [`DebugInfo::address_of`]. The value is read in the target's byte order and is
+    /// returned zero-extended to a `u64`.
+    pub fn read_path<S: memory::Read + ?Sized>(
+        &self,
+        path: &str,
+        memory_source: &mut S,
+    ) -> Result<u64, DebugTypeError> {
+        let (address, kind) = self.address_of(path)?;
+        let base_type =
+            self.base_type_from_item(kind)
+                .ok_or_else(|| DebugTypeError::BaseTypeNotFound {
+                    owner: path.into(),
+                    path: path.into(),
+                    location: Some(address),
+                })?;
+        debug_types::read_unsigned(memory_source, address, base_type.size(), self.endian)
+    }
+
+    /// Return the byte offset of the member at `member_path` from the start of the
+    /// structure or union named `type_name`, without needing an instance of it. The
+    /// type name may include its namespace, and the member path uses the same syntax as
+    /// [`DebugStructure::member_path`], so `regs[2].status` is supported.
+    pub fn offset_of(&self, type_name: &str, member_path: &str) -> Result<u64, DebugTypeError> {
+        use debug_types::MemberContainer;
+
+        let item = self.aggregate_named(type_name)?;
+        let container = if let Ok(structure) = self.structure_from_item_at_address(&item, 0) {
+            MemberContainer::Structure(structure)
+        } else if let Some(index) = self.symbol_unit_mapping.get(&item)
+            && let Some(union) = self.units[*index].union_from_item(item)
+        {
+            MemberContainer::Union(DebugUnion::new(
+                &self.units[*index],
+                self,
+                union,
+                Some(unit_info::MemoryLocation(0)),
+            ))
+        } else {
+            // Enumerations have no members to take the offset of.
+            return Err(DebugTypeError::StructureNotFound {
+                owner: type_name.into(),
+                path: member_path.into(),
+                location: None,
+            });
+        };
+        container.offset_of(member_path)
+    }
+
+    /// Describe how the structure, union or enumeration named `type_name` is laid out in
+    /// memory, in the style of `pahole`. Each member is listed with its offset, size and
+    /// type, along with any holes between members and the padding at the end. Enumerations
+    /// list their discriminant and each variant, and note the largest variant. Nested
+    /// aggregates are not expanded; use [Self::layout_of_with_depth] for that.
+    pub fn layout_of(&self, type_name: &str) -> Result<String, DebugTypeError> {
+        self.layout_of_with_depth(type_name, 0)
+    }
+
+    /// Like [Self::layout_of], but expand members that are themselves structures, unions
+    /// or enumerations up to `depth` levels deep. Offsets of expanded members are
+    /// relative to the start of the outermost type.
+    pub fn layout_of_with_depth(
+        &self,
+        type_name: &str,
+        depth: usize,
+    ) -> Result<String, DebugTypeError> {
+        layout::describe(self, self.aggregate_named(type_name)?, depth).ok_or_else(|| {
+            DebugTypeError::StructureNotFound {
+                owner: type_name.into(),
+                path: String::new(),
+                location: None,
+            }
+        })
+    }
+
+    /// Generate a C definition of the structure, union or enumeration named `type_name`,
+    /// for sharing the type with C code. Members are listed in order of their offset,
+    /// with explicit padding fields wherever the compiler left a hole, and base types
+    /// become fixed-width integers such as `uint32_t`. Any types that are contained by
+    /// value are defined first, and types that are only pointed to are declared.
+    ///
+    /// Each definition is followed by a `_Static_assert` on its size. Types that C can't
+    /// express, such as Rust enumerations with fields or slices, are reported with
+    /// [DebugTypeError::Unrepresentable] naming the member that uses them. Pointers to
+    /// such enumerations are still allowed, and point to an opaque `struct`, while
+    /// pointers to `()` become `void *`.
+    pub fn to_c_declaration(&self, type_name: &str) -> Result<String, DebugTypeError> {
+        codegen::c_declaration(self, self.aggregate_named(type_name)?)
+    }
+
+    /// Generate Rust definitions of the structure, union or enumeration named
+    /// `type_name` and of every type that it contains, for parsing memory dumps on the
+    /// host. Structures become `#[repr(C)]` structures with their fields in order of
+    /// offset and explicit padding fields, and each is followed by a compile-time check
+    /// of its size. Pointers become `u32` or `u64` to match the target, and booleans and
+    /// characters become integers, so that any bytes are a valid value. C-style
+    /// enumerations become a wrapper around their discriminant with a constant for each
+    /// variant.
+    ///
+    /// As with [Self::to_c_declaration], enumerations with fields and slices can't be
+    /// expressed and are reported with [DebugTypeError::Unrepresentable].
+    pub fn to_rust_definition(&self, type_name: &str) -> Result<String, DebugTypeError> {
+        codegen::rust_definition(self, self.aggregate_named(type_name)?)
+    }
+
+    /// Find the structure, union or enumeration named `type_name`. An exact match on the
+    /// qualified name is preferred, and otherwise the bare name is accepted as long as it
+    /// is unambiguous.
+    fn aggregate_named(&self, type_name: &str) -> Result<unit_info::DebugItem, DebugTypeError> {
+        for qualified in [true, false] {
+            let matches = |namespace: &str, name: &str| {
+                if qualified {
+                    qualified_name(namespace, name) == type_name
+                } else {
+                    name == type_name
+                }
+            };
+            let mut found: Vec<(String, unit_info::DebugItem)> = self
+                .structures()
+                .filter(|entry| matches(entry.namespace(), entry.name()))
+                .map(|entry| {
+                    (
+                        qualified_name(entry.namespace(), entry.name()),
+                        entry.item(),
+                    )
+                })
+                .chain(
+                    self.unions()
+                        .filter(|entry| matches(entry.namespace(), entry.name()))
+                        .map(|entry| {
+                            (
+                                qualified_name(entry.namespace(), entry.name()),
+                                entry.item(),
+                            )
+                        }),
+                )
+                .chain(
+                    self.enumerations()
+                        .filter(|entry| matches(entry.namespace(), entry.name()))
+                        .map(|entry| {
+                            (
+                                qualified_name(entry.namespace(), entry.name()),
+                                entry.item(),
+                            )
+                        }),
+                )
+                .collect();
+            match found.len() {
+                0 => continue,
+                1 => return Ok(found.pop().unwrap().1),
+                _ => {
+                    return Err(DebugTypeError::MultipleMatches {
+                        path: type_name.into(),
+                        candidates: found.into_iter().map(|(name, _)| name).collect(),
+                        location: None,
+                    });
+                }
+            }
+        }
+        Err(DebugTypeError::StructureNotFound {
+            owner: type_name.into(),
+            path: String::new(),
+            location: None,
+        })
+    }
+
+    /// Consult all units to look for variables whose demangled name matches `pattern`.
+    /// The pattern supports `*`, which matches any run of characters, and `?`, which
+    /// matches a single character. For example, `*::GAUGE` will find the `GAUGE` static
+    /// regardless of which crate it ended up in.
+    pub fn variables_matching(&self, pattern: &str) -> Vec<DebugVariable<'_>> {
+        self.variables_where(|name| glob_matches(pattern, name))
+            .into_iter()
+            .map(|(_, variable)| variable)
+            .collect()
+    }
+
+    /// Consult all units to look for variables whose demangled name contains `substr`.
+    pub fn variables_containing(&self, substr: &str) -> Vec<DebugVariable<'_>> {
+        self.variables_where(|name| name.contains(substr))
+            .into_iter()
+            .map(|(_, variable)| variable)
+            .collect()
+    }
+
+    /// Consult all units to look for exactly one variable whose demangled name matches
+    /// `pattern`, using the same syntax as [`DebugInfo::variables_matching`]. If more
+    /// than one variable matches, the error lists all of the candidates.
+    pub fn unique_variable_matching(
+        &self,
+        pattern: &str,
+    ) -> Result<DebugVariable<'_>, DebugTypeError> {
+        let mut results = self.variables_where(|name| glob_matches(pattern, name));
+        match results.len() {
+            0 => Err(DebugTypeError::VariableNotFound(pattern.into())),
+            1 => Ok(results.pop().unwrap().1),
+            _ => Err(DebugTypeError::MultipleMatches {
+                path: pattern.into(),
+                candidates: results
+                    .into_iter()
+                    .map(|(name, _)| name.to_owned())
+                    .collect(),
+                location: None,
+            }),
+        }
+    }
+
+    /// Collect every variable in every unit whose demangled name satisfies `matches`.
+    fn variables_where(&self, matches: impl Fn(&str) -> bool) -> Vec<(&str, DebugVariable<'_>)> {
+        let mut results = vec![];
+        for unit in &self.units {
+            for (name, variable) in unit.variables_matching_demangled_name(&matches) {
+                results.push((name, DebugVariable::new(unit, self, variable)));
+            }
+        }
+        results
+    }
+
     /// Consult all units to look for a variant with the specified name. If the variable
     /// cannot be found, return an error. The variable name will not be demangled.
     pub fn variable_from_name(&self, path: &str) -> Result<DebugVariable<'_>, DebugTypeError> {
@@ -235,7 +1497,14 @@ impl DebugInfo {
         match results.len() {
             0 => Err(DebugTypeError::VariableNotFound(path.into())),
             1 => Ok(results.pop().unwrap()),
-            _ => Err(DebugTypeError::MultipleMatches),
+            _ => Err(DebugTypeError::MultipleMatches {
+                path: path.into(),
+                candidates: results
+                    .iter()
+                    .map(|variable| variable.name().to_owned())
+                    .collect(),
+                location: None,
+            }),
         }
     }
 
@@ -252,7 +1521,33 @@ impl DebugInfo {
         results
     }
 
-    pub fn find_variable<P>(&self, predicate: P) -> Result<DebugVariable<'_>, DebugTypeError>
+    /// Consult all units to look for every variable with the specified name, matching either
+    /// the demangled or the raw name. The same variable may be described by several units, so
+    /// variables are deduplicated by address. Variables without a fixed address are always
+    /// returned. Results are ordered by the unit they came from.
+    pub fn find_all_variables(&self, path: &str) -> Vec<DebugVariable<'_>> {
+        let mut seen = HashSet::new();
+        let mut results = vec![];
+        for unit in &self.units {
+            let variables = unit
+                .variables_from_demangled_name(path)
+                .into_iter()
+                .chain(unit.variables_from_name(path));
+            for variable in variables {
+                if variable
+                    .location()
+                    .is_none_or(|location| seen.insert(location))
+                {
+                    results.push(DebugVariable::new(unit, self, variable));
+                }
+            }
+        }
+        results
+    }
+
+    /// Return the first variable in any unit that satisfies `predicate`. Use
+    /// [Self::find_variables] to see every match.
+    pub fn find_first_variable<P>(&self, predicate: P) -> Result<DebugVariable<'_>, DebugTypeError>
     where
         Self: Sized,
         P: Fn(&&Variable) -> bool,
@@ -265,6 +1560,72 @@ impl DebugInfo {
         Err(DebugTypeError::VariableNotFound("".into()))
     }
 
+    /// Return the first variable in any unit that satisfies `predicate`.
+    #[deprecated(note = "use find_first_variable")]
+    pub fn find_variable<P>(&self, predicate: P) -> Result<DebugVariable<'_>, DebugTypeError>
+    where
+        Self: Sized,
+        P: Fn(&&Variable) -> bool,
+    {
+        self.find_first_variable(predicate)
+    }
+
+    /// Lazily iterate over every variable in every unit that satisfies `predicate`.
+    /// Variables that several units describe are returned once for each unit.
+    pub fn find_variables<P>(&self, predicate: P) -> impl Iterator<Item = DebugVariable<'_>>
+    where
+        P: Fn(&&Variable) -> bool,
+    {
+        self.units
+            .iter()
+            .flat_map(|unit| {
+                unit.variables()
+                    .iter()
+                    .map(move |variable| (unit, variable))
+            })
+            .filter(move |(_, variable)| predicate(variable))
+            .map(move |(unit, variable)| DebugVariable::new(unit, self, variable))
+    }
+
+    /// Iterate over every variable that was declared in a source file whose path ends
+    /// with `file_suffix`, such as `drivers/uart.c`, to see the state of one module. The
+    /// suffix must start at a path component, so `uart.c` doesn't match `myuart.c`.
+    pub fn variables_in_file<'a>(
+        &'a self,
+        file_suffix: &str,
+    ) -> impl Iterator<Item = DebugVariable<'a>> + use<'a> {
+        let file_suffix = file_suffix.to_owned();
+        self.find_variables(move |variable| {
+            variable.file().is_some_and(|file| {
+                file.strip_suffix(file_suffix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.ends_with(['/', '\\']))
+            })
+        })
+    }
+
+    /// Consult all units for a type with the namespace and name given in `kind`, such as
+    /// `my_crate::Foo`, using `lookup` to find the type belonging to a particular item.
+    fn type_named<T>(
+        &self,
+        kind: &str,
+        lookup: fn(&UnitInfo, unit_info::DebugItem) -> Option<&T>,
+        name_of: fn(&T) -> (&str, &str),
+    ) -> Option<(&UnitInfo, &T)> {
+        let (namespace, name) = split_namespace_and_name(kind);
+        if namespace.is_empty() {
+            return None;
+        }
+
+        // Multiple DIEs can represent the same type if the type is used across multiple
+        // compilation units. We return the first DIE that we come across, although I'm not sure
+        // if this is correct in all cases.
+        self.symbol_unit_mapping.iter().find_map(|(item, index)| {
+            let unit = self.units.get(*index)?;
+            let value = lookup(unit, *item)?;
+            (name_of(value) == (namespace, name)).then_some((unit, value))
+        })
+    }
+
     /// Consult all units to look for a structure with the specified name. If the structure
     /// cannot be found, return an error. If it's found, construct a new [Structure] at the
     /// specified address.
@@ -273,55 +1634,49 @@ impl DebugInfo {
         kind: &str,
         address: u64,
     ) -> Result<DebugStructure<'_>, DebugTypeError> {
-        let (namespace, name) = split_namespace_and_name(kind);
-
-        if namespace.is_empty() {
-            return Err(DebugTypeError::StructureNotFound {
+        self.structure_from_type(kind)
+            .map(|structure| structure.with_address(address))
+            .map_err(|_| DebugTypeError::StructureNotFound {
                 owner: kind.to_owned(),
-                path: format!("0x{address:x}"),
-            });
-        }
-
-        for (item, index) in &self.symbol_unit_mapping {
-            let Some(unit) = self.units.get(*index) else {
-                continue;
-            };
-            let Some(structure) = unit.structure_from_item(*item) else {
-                continue;
-            };
-
-            if structure.namespace() != namespace || structure.name() != name {
-                continue;
-            }
-
-            // Multiple DIEs can represent the same struct if the struct is used across multiple
-            // compilation units. We return the first DIE that we come across, although I'm not sure
-            // if this is correct in all cases.
-            return Ok(DebugStructure::new(
-                unit,
-                self,
-                structure,
-                unit_info::MemoryLocation(address),
-            ));
-        }
+                path: String::new(),
+                location: Some(address),
+            })
+    }
 
-        Err(DebugTypeError::StructureNotFound {
+    /// Look up a structure by name without placing it in memory, for inspecting its
+    /// layout. Members of the returned structure have no location, so reading them
+    /// fails with [DebugTypeError::LocationMissing].
+    pub fn structure_from_type(&self, kind: &str) -> Result<DebugStructure<'_>, DebugTypeError> {
+        // Declarations are skipped so that the full definition is found regardless of
+        // which unit is searched first.
+        self.type_named(
+            kind,
+            |unit, item| {
+                unit.structure_from_item(item)
+                    .filter(|s| !s.is_declaration())
+            },
+            |structure| (structure.namespace(), structure.name()),
+        )
+        .map(|(unit, structure)| DebugStructure::new(unit, self, structure, None))
+        .ok_or_else(|| DebugTypeError::StructureNotFound {
             owner: kind.to_owned(),
-            path: format!("0x{address:x}"),
+            path: String::new(),
+            location: None,
         })
     }
 
     /// Consult all units to find a structure whose namespace and name
     /// satisfy the predicate. Unlike [Self::structure_from_type_at_address],
     /// this can find structures with empty namespaces (tuples, references),
-    /// and leaves name comparison to the caller.
+    /// and leaves name comparison to the caller. Declarations are skipped, so
+    /// only complete definitions are returned.
     pub fn find_structure_by_name<P>(&self, predicate: P) -> Option<&unit_info::Structure>
     where
         P: Fn(&str, &str) -> bool,
     {
-        self.units
-            .iter()
-            .find_map(|unit| unit.find_structure(|s| predicate(s.namespace(), s.name())))
+        self.units.iter().find_map(|unit| {
+            unit.find_structure(|s| !s.is_declaration() && predicate(s.namespace(), s.name()))
+        })
     }
 
     /// Consult all units to find a base type by name, returning its
@@ -337,6 +1692,7 @@ impl DebugInfo {
         target_item: &unit_info::DebugItem,
         address: u64,
     ) -> Result<DebugStructure<'_>, DebugTypeError> {
+        let target_item = &self.resolve_declaration(*target_item);
         for (item, index) in &self.symbol_unit_mapping {
             if target_item != item {
                 continue;
@@ -356,13 +1712,14 @@ impl DebugInfo {
                 unit,
                 self,
                 structure,
-                unit_info::MemoryLocation(address),
+                Some(unit_info::MemoryLocation(address)),
             ));
         }
 
         Err(DebugTypeError::StructureNotFound {
             owner: "".to_owned(),
-            path: format!("0x{address:x}"),
+            path: String::new(),
+            location: Some(address),
         })
     }
 
@@ -374,38 +1731,29 @@ impl DebugInfo {
         kind: &str,
         address: u64,
     ) -> Result<DebugEnumeration<'_>, DebugTypeError> {
-        let (namespace, name) = split_namespace_and_name(kind);
-
-        if namespace.is_empty() {
-            return Err(DebugTypeError::StructureNotFound {
+        self.enumeration_from_type(kind)
+            .map(|enumeration| enumeration.with_address(address))
+            .map_err(|_| DebugTypeError::EnumerationNotFound {
                 owner: kind.to_owned(),
-                path: format!("0x{address:x}"),
-            });
-        }
-
-        for (item, index) in &self.symbol_unit_mapping {
-            let Some(unit) = self.units.get(*index) else {
-                continue;
-            };
-            let Some(enumeration) = unit.enumeration_from_item(*item) else {
-                continue;
-            };
-
-            if enumeration.namespace() != namespace || enumeration.name() != name {
-                continue;
-            }
-
-            return Ok(DebugEnumeration::new(
-                unit,
-                self,
-                enumeration,
-                unit_info::MemoryLocation(address),
-            ));
-        }
+                path: String::new(),
+                location: Some(address),
+            })
+    }
 
-        Err(DebugTypeError::EnumerationNotFound {
+    /// Look up an enumeration by name without placing it in memory. The variants can be
+    /// listed, but the current variant can't be read.
+    pub fn enumeration_from_type(
+        &self,
+        kind: &str,
+    ) -> Result<DebugEnumeration<'_>, DebugTypeError> {
+        self.type_named(kind, UnitInfo::enumeration_from_item, |enumeration| {
+            (enumeration.namespace(), enumeration.name())
+        })
+        .map(|(unit, enumeration)| DebugEnumeration::new(unit, self, enumeration, None))
+        .ok_or_else(|| DebugTypeError::EnumerationNotFound {
             owner: kind.to_owned(),
-            path: format!("0x{address:x}"),
+            path: String::new(),
+            location: None,
         })
     }
 
@@ -417,38 +1765,25 @@ impl DebugInfo {
         kind: &str,
         address: u64,
     ) -> Result<DebugUnion<'_>, DebugTypeError> {
-        let (namespace, name) = split_namespace_and_name(kind);
-
-        if namespace.is_empty() {
-            return Err(DebugTypeError::StructureNotFound {
+        self.union_from_type(kind)
+            .map(|union| union.with_address(address))
+            .map_err(|_| DebugTypeError::UnionNotFound {
                 owner: kind.to_owned(),
-                path: format!("0x{address:x}"),
-            });
-        }
-
-        for (item, index) in &self.symbol_unit_mapping {
-            let Some(unit) = self.units.get(*index) else {
-                continue;
-            };
-            let Some(union) = unit.union_from_item(*item) else {
-                continue;
-            };
-
-            if union.namespace() != namespace || union.name() != name {
-                continue;
-            }
-
-            return Ok(DebugUnion::new(
-                unit,
-                self,
-                union,
-                unit_info::MemoryLocation(address),
-            ));
-        }
+                path: String::new(),
+                location: Some(address),
+            })
+    }
 
-        Err(DebugTypeError::UnionNotFound {
+    /// Look up a union by name without placing it in memory, for inspecting its members.
+    pub fn union_from_type(&self, kind: &str) -> Result<DebugUnion<'_>, DebugTypeError> {
+        self.type_named(kind, UnitInfo::union_from_item, |union| {
+            (union.namespace(), union.name())
+        })
+        .map(|(unit, union)| DebugUnion::new(unit, self, union, None))
+        .ok_or_else(|| DebugTypeError::UnionNotFound {
             owner: kind.to_owned(),
-            path: format!("0x{address:x}"),
+            path: String::new(),
+            location: None,
         })
     }
 
@@ -472,21 +1807,23 @@ impl DebugInfo {
             return Ok(DebugBaseType::from_base_type(
                 Some(MemoryLocation(address)),
                 base_type,
+                self.endian,
             ));
         }
 
         Err(DebugTypeError::BaseTypeNotFound {
             owner: "".to_string(),
-            path: format!("0x{address:x}"),
+            path: String::new(),
+            location: Some(address),
         })
     }
 
     /// Get the size of the specified debug item. Any debug item may be specified here,
-    /// though some types may return `None` if their size couldn't be determined.
+    /// though some types may return `None` if their size couldn't be determined. Arrays
+    /// are the size of their elements times their count, and pointers the size of an
+    /// address on the target.
     pub fn size_from_item(&self, item: unit_info::DebugItem) -> Option<unit_info::StructOffset> {
-        self.symbol_unit_mapping
-            .get(&item)
-            .and_then(|var| self.units[*var].size_from_item(item))
+        self.byte_size(item).map(unit_info::StructOffset)
     }
 
     /// Given an item, return the Variable object. If the item is not a Variable, or couldn't
@@ -500,6 +1837,7 @@ impl DebugInfo {
     /// Given an item, return the Structure object. If the item is not a Structure, or couldn't
     /// be located, return `None`.
     pub fn structure_from_item(&self, item: unit_info::DebugItem) -> Option<&unit_info::Structure> {
+        let item = self.resolve_item(item);
         self.symbol_unit_mapping
             .get(&item)
             .and_then(|var| self.units[*var].structure_from_item(item))
@@ -511,6 +1849,7 @@ impl DebugInfo {
         &self,
         item: unit_info::DebugItem,
     ) -> Option<&unit_info::Enumeration> {
+        let item = self.resolve_item(item);
         self.symbol_unit_mapping
             .get(&item)
             .and_then(|var| self.units[*var].enumeration_from_item(item))
@@ -519,14 +1858,28 @@ impl DebugInfo {
     /// Given an item, return the Pointer object. If the item is not a Pointer, or couldn't
     /// be located, return `None`.
     pub fn pointer_from_item(&self, item: unit_info::DebugItem) -> Option<&unit_info::Pointer> {
+        let item = self.resolve_item(item);
         self.symbol_unit_mapping
             .get(&item)
             .and_then(|var| self.units[*var].pointer_from_item(item))
     }
 
+    /// Given an item, return the SubroutineType object. If the item is not a SubroutineType,
+    /// or couldn't be located, return `None`.
+    pub fn subroutine_type_from_item(
+        &self,
+        item: unit_info::DebugItem,
+    ) -> Option<&unit_info::SubroutineType> {
+        let item = self.resolve_item(item);
+        self.symbol_unit_mapping
+            .get(&item)
+            .and_then(|var| self.units[*var].subroutine_type_from_item(item))
+    }
+
     /// Given an item, return the Array object. If the item is not an Array, or couldn't
     /// be located, return `None`.
     pub fn array_from_item(&self, item: unit_info::DebugItem) -> Option<&unit_info::Array> {
+        let item = self.resolve_item(item);
         self.symbol_unit_mapping
             .get(&item)
             .and_then(|var| self.units[*var].array_from_item(item))
@@ -535,16 +1888,119 @@ impl DebugInfo {
     /// Given an item, return the Union object. If the item is not a Union, or couldn't
     /// be located, return `None`.
     pub fn union_from_item(&self, item: unit_info::DebugItem) -> Option<&unit_info::Union> {
+        let item = self.resolve_item(item);
         self.symbol_unit_mapping
             .get(&item)
             .and_then(|var| self.units[*var].union_from_item(item))
     }
 
+    /// Given an item, return the TypeAlias object. If the item is not a typedef or type
+    /// qualifier, or couldn't be located, return `None`.
+    pub fn type_alias_from_item(
+        &self,
+        item: unit_info::DebugItem,
+    ) -> Option<&unit_info::TypeAlias> {
+        self.symbol_unit_mapping
+            .get(&item)
+            .and_then(|var| self.units[*var].type_alias_from_item(item))
+    }
+
+    /// Follow `typedef`, `const`, `volatile` and `restrict` wrappers until reaching the
+    /// type underneath, such as a structure or base type. Items that aren't wrapped are
+    /// returned unchanged. A chain that ends without a type, such as `const void`, returns
+    /// the last wrapper in the chain.
+    pub fn resolve_type_chain(&self, item: unit_info::DebugItem) -> unit_info::DebugItem {
+        let mut item = item;
+        for _ in 0..MAX_TYPE_CHAIN {
+            match self
+                .type_alias_from_item(item)
+                .and_then(|alias| alias.kind())
+            {
+                Some(kind) => item = kind,
+                None => break,
+            }
+        }
+        item
+    }
+
     /// Given an item, return the BaseType object. If the item is not a BaseType, or couldn't
     /// be located, return `None`.
     pub fn base_type_from_item(&self, item: unit_info::DebugItem) -> Option<&unit_info::BaseType> {
+        let item = self.resolve_item(item);
         self.symbol_unit_mapping
             .get(&item)
             .and_then(|var| self.units[*var].base_type_from_item(item))
     }
+
+    /// Given an item, return the name of its type including the namespace, such as
+    /// `core::option::Option<u32>`. Pointers without a name of their own are shown as
+    /// `*` followed by the type they point to, and arrays have each dimension appended
+    /// as `[N]`. Types reached through a typedef are given the typedef's name, so that
+    /// `typedef struct { .. } foo_t` is called `foo_t`. If the item is not a type, or
+    /// couldn't be located, return `None`.
+    pub fn full_qualified_name_from_item(&self, item: unit_info::DebugItem) -> Option<String> {
+        // Qualifiers such as `const` may wrap the typedef, so look along the whole chain.
+        let mut alias = item;
+        for _ in 0..MAX_TYPE_CHAIN {
+            let Some(type_alias) = self.type_alias_from_item(alias) else {
+                break;
+            };
+            if let Some(name) = type_alias.name() {
+                return Some(name.to_owned());
+            }
+            match type_alias.kind() {
+                Some(kind) => alias = kind,
+                None => break,
+            }
+        }
+        let item = self.resolve_item(item);
+        if let Some(structure) = self.structure_from_item(item) {
+            return Some(qualified_name(structure.namespace(), structure.name()));
+        }
+        if let Some(enumeration) = self.enumeration_from_item(item) {
+            return Some(qualified_name(enumeration.namespace(), enumeration.name()));
+        }
+        if let Some(union) = self.union_from_item(item) {
+            return Some(qualified_name(union.namespace(), union.name()));
+        }
+        if let Some(base_type) = self.base_type_from_item(item) {
+            return Some(qualified_name(base_type.namespace(), base_type.name()));
+        }
+        if let Some(pointer) = self.pointer_from_item(item) {
+            if let Some(name) = pointer.name() {
+                return Some(name.to_owned());
+            }
+            return match pointer.kind() {
+                Some(kind) => Some(format!("*{}", self.full_qualified_name_from_item(kind)?)),
+                None => Some("*const void".to_owned()),
+            };
+        }
+        if let Some(array) = self.array_from_item(item) {
+            // Arrays of arrays are shown with the outermost dimension first.
+            let mut dimensions = String::new();
+            let mut element = array;
+            loop {
+                for (_, count) in element.dimensions() {
+                    dimensions.push_str(&format!("[{count}]"));
+                }
+                match self.array_from_item(element.kind()) {
+                    Some(inner) => element = inner,
+                    None => break,
+                }
+            }
+            let element_name = self.full_qualified_name_from_item(element.kind())?;
+            return Some(format!("{element_name}{dimensions}"));
+        }
+        self.subroutine_type_from_item(item)
+            .map(|_| "fn()".to_owned())
+    }
+}
+
+/// Join a namespace and a name with `::`, unless the namespace is empty.
+fn qualified_name(namespace: &str, name: &str) -> String {
+    if namespace.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{namespace}::{name}")
+    }
 }
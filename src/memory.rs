@@ -48,25 +48,26 @@ pub trait Read {
 
     /// Read one 128-bit value from the specified address. The address does
     /// not need to be aligned, but performance may be improved if it is.
+    /// This is made up of two 64-bit reads, with the low half first.
     fn read_u128(&mut self, address: u64) -> Result<u128, Self::Error> {
-        Ok(u128::from_le_bytes([
-            self.read_u8(address)?,
-            self.read_u8(address + 1)?,
-            self.read_u8(address + 2)?,
-            self.read_u8(address + 3)?,
-            self.read_u8(address + 4)?,
-            self.read_u8(address + 5)?,
-            self.read_u8(address + 6)?,
-            self.read_u8(address + 7)?,
-            self.read_u8(address + 8)?,
-            self.read_u8(address + 9)?,
-            self.read_u8(address + 10)?,
-            self.read_u8(address + 11)?,
-            self.read_u8(address + 12)?,
-            self.read_u8(address + 13)?,
-            self.read_u8(address + 14)?,
-            self.read_u8(address + 15)?,
-        ]))
+        let low = self.read_u64(address)?;
+        let high = self.read_u64(address + 8)?;
+        Ok(u128::from(high) << 64 | u128::from(low))
+    }
+
+    /// Read one big-endian 16-bit value from the specified address.
+    fn read_u16_be(&mut self, address: u64) -> Result<u16, Self::Error> {
+        Ok(u16::from_be_bytes(self.read_u16(address)?.to_le_bytes()))
+    }
+
+    /// Read one big-endian 32-bit value from the specified address.
+    fn read_u32_be(&mut self, address: u64) -> Result<u32, Self::Error> {
+        Ok(u32::from_be_bytes(self.read_u32(address)?.to_le_bytes()))
+    }
+
+    /// Read one big-endian 64-bit value from the specified address.
+    fn read_u64_be(&mut self, address: u64) -> Result<u64, Self::Error> {
+        Ok(u64::from_be_bytes(self.read_u64(address)?.to_le_bytes()))
     }
 
     /// Read data into the buffer. If an error occurs, then the buffer contents
@@ -88,6 +89,317 @@ pub trait Read {
     fn finish(&mut self) {}
 }
 
+/// Forward every `Read` method to the inner reader so that any specialised
+/// implementations are preserved.
+macro_rules! forward_read {
+    ($inner:ident, $map_err:expr) => {
+        fn read_u8(&mut self, address: u64) -> Result<u8, Self::Error> {
+            self.$inner().read_u8(address).map_err($map_err)
+        }
+
+        fn read_u16(&mut self, address: u64) -> Result<u16, Self::Error> {
+            self.$inner().read_u16(address).map_err($map_err)
+        }
+
+        fn read_u32(&mut self, address: u64) -> Result<u32, Self::Error> {
+            self.$inner().read_u32(address).map_err($map_err)
+        }
+
+        fn read_u64(&mut self, address: u64) -> Result<u64, Self::Error> {
+            self.$inner().read_u64(address).map_err($map_err)
+        }
+
+        fn read_u128(&mut self, address: u64) -> Result<u128, Self::Error> {
+            self.$inner().read_u128(address).map_err($map_err)
+        }
+
+        fn read_u16_be(&mut self, address: u64) -> Result<u16, Self::Error> {
+            self.$inner().read_u16_be(address).map_err($map_err)
+        }
+
+        fn read_u32_be(&mut self, address: u64) -> Result<u32, Self::Error> {
+            self.$inner().read_u32_be(address).map_err($map_err)
+        }
+
+        fn read_u64_be(&mut self, address: u64) -> Result<u64, Self::Error> {
+            self.$inner().read_u64_be(address).map_err($map_err)
+        }
+
+        fn read(&mut self, data: &mut [u8], address: u64) -> Result<(), Self::Error> {
+            self.$inner().read(data, address).map_err($map_err)
+        }
+
+        fn begin(&mut self) -> Result<(), Self::Error> {
+            self.$inner().begin().map_err($map_err)
+        }
+
+        fn finish(&mut self) {
+            self.$inner().finish()
+        }
+    };
+}
+
+trait AsInner {
+    type Inner: Read + ?Sized;
+    fn inner(&mut self) -> &mut Self::Inner;
+}
+
+impl<R: Read + ?Sized> AsInner for &mut R {
+    type Inner = R;
+    fn inner(&mut self) -> &mut R {
+        self
+    }
+}
+
+impl<R: Read + ?Sized> AsInner for Box<R> {
+    type Inner = R;
+    fn inner(&mut self) -> &mut R {
+        self
+    }
+}
+
+impl<R: Read + ?Sized> Read for &mut R {
+    type Error = R::Error;
+    forward_read!(inner, core::convert::identity);
+}
+
+impl<R: Read + ?Sized> Read for Box<R> {
+    type Error = R::Error;
+    forward_read!(inner, core::convert::identity);
+}
+
+/// An error from a reader that has been wrapped in a [`DynRead`].
+#[derive(Debug)]
+pub struct DynReadError(Box<dyn core::error::Error + Send + Sync>);
+
+impl DynReadError {
+    /// Return the error produced by the original reader.
+    pub fn into_inner(self) -> Box<dyn core::error::Error + Send + Sync> {
+        self.0
+    }
+}
+
+impl core::fmt::Display for DynReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl core::error::Error for DynReadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+struct ErasedRead<R>(R);
+
+impl<R: Read> AsInner for ErasedRead<R> {
+    type Inner = R;
+    fn inner(&mut self) -> &mut R {
+        &mut self.0
+    }
+}
+
+impl<R: Read> Read for ErasedRead<R>
+where
+    R::Error: Send + Sync + 'static,
+{
+    type Error = DynReadError;
+    forward_read!(inner, |e| DynReadError(Box::new(e)));
+}
+
+/// A reader with its error type erased, allowing readers with different
+/// error types to be stored and passed around behind the same type.
+pub struct DynRead<'a>(Box<dyn Read<Error = DynReadError> + 'a>);
+
+impl<'a> DynRead<'a> {
+    pub fn new<R: Read + 'a>(reader: R) -> Self
+    where
+        R::Error: Send + Sync + 'static,
+    {
+        DynRead(Box::new(ErasedRead(reader)))
+    }
+}
+
+impl<'a> AsInner for DynRead<'a> {
+    type Inner = dyn Read<Error = DynReadError> + 'a;
+    fn inner(&mut self) -> &mut Self::Inner {
+        &mut *self.0
+    }
+}
+
+impl Read for DynRead<'_> {
+    type Error = DynReadError;
+    forward_read!(inner, core::convert::identity);
+}
+
+/// A reader that fetches memory in blocks of `CACHE_SIZE` bytes and serves
+/// later reads from that block. This greatly reduces the number of
+/// transactions when the target is behind a slow link such as JTAG or a
+/// serial port.
+///
+/// The cache is dropped when [`Read::begin()`] is called, so that a new
+/// burst of reads observes the current contents of memory.
+pub struct BufferedReader<R: Read, const CACHE_SIZE: usize> {
+    inner: R,
+    cache: [u8; CACHE_SIZE],
+    /// The address of the first byte in `cache`, if the cache is valid
+    base: Option<u64>,
+    /// The address of the last block that couldn't be read, whose bytes are read one
+    /// at a time rather than trying to fetch the whole block again
+    failed: Option<u64>,
+}
+
+impl<R: Read, const CACHE_SIZE: usize> BufferedReader<R, CACHE_SIZE> {
+    pub fn new(inner: R) -> Self {
+        const { assert!(CACHE_SIZE > 0, "CACHE_SIZE must not be zero") };
+        BufferedReader {
+            inner,
+            cache: [0; CACHE_SIZE],
+            base: None,
+            failed: None,
+        }
+    }
+
+    /// Discard the cached block, so that the next read goes to the target.
+    pub fn invalidate(&mut self) {
+        self.base = None;
+        self.failed = None;
+    }
+
+    /// Return the reader that this was wrapping.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Return the cached byte at `address`, fetching the block that contains it
+    /// if needed. If the block can't be read, for example because it extends
+    /// past the end of a memory region, the byte is read on its own, as are the
+    /// other bytes of that block until the cache is invalidated.
+    fn cached_u8(&mut self, address: u64) -> Result<u8, R::Error> {
+        let size = CACHE_SIZE as u64;
+        let base = address - address % size;
+        if self.base != Some(base) {
+            if self.failed == Some(base) {
+                return self.inner.read_u8(address);
+            }
+            self.base = None;
+            if self.inner.read(&mut self.cache, base).is_err() {
+                self.failed = Some(base);
+                return self.inner.read_u8(address);
+            }
+            self.base = Some(base);
+        }
+        Ok(self.cache[(address - base) as usize])
+    }
+}
+
+impl<R: Read, const CACHE_SIZE: usize> Read for BufferedReader<R, CACHE_SIZE> {
+    type Error = R::Error;
+
+    fn read_u8(&mut self, address: u64) -> Result<u8, Self::Error> {
+        self.cached_u8(address)
+    }
+
+    fn begin(&mut self) -> Result<(), Self::Error> {
+        self.invalidate();
+        self.inner.begin()
+    }
+
+    fn finish(&mut self) {
+        self.inner.finish()
+    }
+}
+
+/// A reader whose `read_u16()`, `read_u32()`, `read_u64()` and `read_u128()` return
+/// values in the byte order chosen when it was created, rather than always
+/// little-endian. This is useful for reading raw values from a big-endian target.
+///
+/// The big-endian methods such as `read_u32_be()` are passed through unchanged. The
+/// types in [`crate::debug_types`] only use those, so this can also be passed to them
+/// without the bytes being swapped a second time.
+pub struct EndiannessAwareReader<R: Read> {
+    inner: R,
+    endian: gimli::RunTimeEndian,
+}
+
+impl<R: Read> EndiannessAwareReader<R> {
+    pub fn new(inner: R, endian: gimli::RunTimeEndian) -> Self {
+        EndiannessAwareReader { inner, endian }
+    }
+
+    /// The byte order that values are read in.
+    pub fn endian(&self) -> gimli::RunTimeEndian {
+        self.endian
+    }
+
+    /// Return the reader that this was wrapping.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for EndiannessAwareReader<R> {
+    type Error = R::Error;
+
+    fn read_u8(&mut self, address: u64) -> Result<u8, Self::Error> {
+        self.inner.read_u8(address)
+    }
+
+    fn read_u16(&mut self, address: u64) -> Result<u16, Self::Error> {
+        match self.endian {
+            gimli::RunTimeEndian::Little => self.inner.read_u16(address),
+            gimli::RunTimeEndian::Big => self.inner.read_u16_be(address),
+        }
+    }
+
+    fn read_u32(&mut self, address: u64) -> Result<u32, Self::Error> {
+        match self.endian {
+            gimli::RunTimeEndian::Little => self.inner.read_u32(address),
+            gimli::RunTimeEndian::Big => self.inner.read_u32_be(address),
+        }
+    }
+
+    fn read_u64(&mut self, address: u64) -> Result<u64, Self::Error> {
+        match self.endian {
+            gimli::RunTimeEndian::Little => self.inner.read_u64(address),
+            gimli::RunTimeEndian::Big => self.inner.read_u64_be(address),
+        }
+    }
+
+    fn read_u128(&mut self, address: u64) -> Result<u128, Self::Error> {
+        let value = self.inner.read_u128(address)?;
+        Ok(match self.endian {
+            gimli::RunTimeEndian::Little => value,
+            gimli::RunTimeEndian::Big => value.swap_bytes(),
+        })
+    }
+
+    fn read_u16_be(&mut self, address: u64) -> Result<u16, Self::Error> {
+        self.inner.read_u16_be(address)
+    }
+
+    fn read_u32_be(&mut self, address: u64) -> Result<u32, Self::Error> {
+        self.inner.read_u32_be(address)
+    }
+
+    fn read_u64_be(&mut self, address: u64) -> Result<u64, Self::Error> {
+        self.inner.read_u64_be(address)
+    }
+
+    fn read(&mut self, data: &mut [u8], address: u64) -> Result<(), Self::Error> {
+        self.inner.read(data, address)
+    }
+
+    fn begin(&mut self) -> Result<(), Self::Error> {
+        self.inner.begin()
+    }
+
+    fn finish(&mut self) {
+        self.inner.finish()
+    }
+}
+
 /// Write data to the device. This is currently unused in tasru.
 pub trait Write {
     type Error: core::error::Error;
@@ -151,3 +463,86 @@ where
     T: Read<Error = E> + Write<Error = E>,
 {
 }
+
+/// An error returned by [`IhexReader`].
+#[cfg(feature = "ihex")]
+#[derive(Debug)]
+pub enum IhexError {
+    /// The Intel HEX data could not be parsed
+    Parse(ihex::ReaderError),
+    /// The address is not covered by any data record
+    Unmapped(u64),
+}
+
+#[cfg(feature = "ihex")]
+impl core::fmt::Display for IhexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IhexError::Parse(e) => write!(f, "Unable to parse Intel HEX data: {}", e),
+            IhexError::Unmapped(address) => {
+                write!(f, "Address {:#010x} is not present in the image", address)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ihex")]
+impl core::error::Error for IhexError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            IhexError::Parse(e) => Some(e),
+            IhexError::Unmapped(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "ihex")]
+impl From<ihex::ReaderError> for IhexError {
+    fn from(value: ihex::ReaderError) -> Self {
+        IhexError::Parse(value)
+    }
+}
+
+/// A memory image loaded from an Intel HEX file, such as a flash snapshot
+/// produced by a build system. Reads from addresses that are not covered by
+/// the image return [`IhexError::Unmapped`].
+#[cfg(feature = "ihex")]
+#[derive(Debug, Default, Clone)]
+pub struct IhexReader {
+    data: std::collections::BTreeMap<u64, u8>,
+}
+
+#[cfg(feature = "ihex")]
+impl IhexReader {
+    /// Parse the contents of an Intel HEX file.
+    pub fn new(contents: &str) -> Result<Self, IhexError> {
+        let mut data = std::collections::BTreeMap::new();
+        let mut base = 0u64;
+        for record in ihex::Reader::new(contents) {
+            match record? {
+                ihex::Record::Data { offset, value } => {
+                    for (index, byte) in value.into_iter().enumerate() {
+                        data.insert(base + u64::from(offset) + index as u64, byte);
+                    }
+                }
+                ihex::Record::ExtendedSegmentAddress(segment) => base = u64::from(segment) << 4,
+                ihex::Record::ExtendedLinearAddress(upper) => base = u64::from(upper) << 16,
+                ihex::Record::EndOfFile => break,
+                ihex::Record::StartSegmentAddress { .. } | ihex::Record::StartLinearAddress(_) => {}
+            }
+        }
+        Ok(IhexReader { data })
+    }
+}
+
+#[cfg(feature = "ihex")]
+impl Read for IhexReader {
+    type Error = IhexError;
+
+    fn read_u8(&mut self, address: u64) -> Result<u8, Self::Error> {
+        self.data
+            .get(&address)
+            .copied()
+            .ok_or(IhexError::Unmapped(address))
+    }
+}
@@ -0,0 +1,98 @@
+//! Reports on how much memory the static variables of a program use, built from the
+//! debug information rather than the symbol table, so that every variable has a source
+//! file and namespace to be grouped by.
+
+use std::collections::HashMap;
+
+/// A section of the Elf file that occupies memory on the target, such as `.data` or
+/// `.bss`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Section {
+    pub name: String,
+    pub address: u64,
+    pub size: u64,
+}
+
+impl Section {
+    /// Whether `address` falls within this section.
+    pub fn contains(&self, address: u64) -> bool {
+        address >= self.address && address - self.address < self.size
+    }
+}
+
+/// One static variable, as returned by [`DebugInfo::static_size_report()`].
+///
+/// [`DebugInfo::static_size_report()`]: crate::DebugInfo::static_size_report
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportRow {
+    /// The name of the variable without its namespace, such as `COUNTER`.
+    pub name: String,
+    /// The namespace that the variable was declared in, such as `my_crate::drivers`.
+    /// This is empty for C variables.
+    pub namespace: String,
+    /// The source file that declared the variable, if the compiler recorded it.
+    pub file: Option<String>,
+    pub address: u64,
+    /// The size of the variable, if the size of its type is known.
+    pub size: Option<u64>,
+    /// The allocated section that the variable is in. This is `None` if the address is
+    /// outside of every allocated section, which usually means that the debug information
+    /// doesn't match the Elf file. It is also `None` if the sections aren't known, such
+    /// as when the debug information was loaded with [`DebugInfo::from_section_map()`].
+    ///
+    /// [`DebugInfo::from_section_map()`]: crate::DebugInfo::from_section_map
+    pub section: Option<String>,
+}
+
+impl ReportRow {
+    /// The first component of the namespace, which for Rust is the crate that the
+    /// variable belongs to.
+    pub fn crate_name(&self) -> &str {
+        self.namespace.split("::").next().unwrap_or_default()
+    }
+}
+
+/// The combined size of a group of variables, as returned by [`totals_by()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportTotal {
+    pub group: String,
+    pub variables: usize,
+    /// The sum of the sizes of the variables whose size is known.
+    pub size: u64,
+}
+
+/// Add up the sizes of `rows` grouped by `key`, largest first. For example,
+/// `totals_by(&rows, ReportRow::crate_name)` gives the memory used by each crate, and
+/// `totals_by(&rows, |row| &row.namespace)` the memory used by each module.
+pub fn totals_by(rows: &[ReportRow], key: impl Fn(&ReportRow) -> &str) -> Vec<ReportTotal> {
+    let mut totals: HashMap<&str, ReportTotal> = HashMap::new();
+    for row in rows {
+        let group = key(row);
+        let total = totals.entry(group).or_insert_with(|| ReportTotal {
+            group: group.to_owned(),
+            variables: 0,
+            size: 0,
+        });
+        total.variables += 1;
+        total.size += row.size.unwrap_or(0);
+    }
+    let mut totals: Vec<_> = totals.into_values().collect();
+    totals.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.group.cmp(&b.group)));
+    totals
+}
+
+/// How much of an allocated section is taken up by variables that the debug
+/// information describes, as returned by [`DebugInfo::section_usage()`]. Whatever is
+/// left over belongs to variables without debug information, or is padding.
+///
+/// [`DebugInfo::section_usage()`]: crate::DebugInfo::section_usage
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SectionUsage {
+    pub section: Section,
+    pub variables: usize,
+    pub used: u64,
+}
@@ -1,10 +1,11 @@
 use gimli::{DW_AT_name, Endianity, Reader, SectionId, UnitSectionOffset};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use crate::{GimliReader, split_namespace_and_name};
+use crate::{DebugInfoOptions, GimliReader, split_namespace_and_name};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 /// A location within the debug section
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DebugItem {
     pub offset: u64,
 }
@@ -26,10 +27,16 @@ impl DebugItem {
             offset: offset.0 as u64,
         }
     }
+
+    /// Move this item by `base`, so that items from several Elf files don't overlap.
+    fn rebase(&mut self, base: u64) {
+        self.offset += base;
+    }
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 /// A location within the running target
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryLocation(pub u64);
 
 impl core::fmt::Display for MemoryLocation {
@@ -38,6 +45,13 @@ impl core::fmt::Display for MemoryLocation {
     }
 }
 
+impl MemoryLocation {
+    /// Add `rhs` to this location, returning `None` if the result would overflow.
+    pub fn checked_add(self, rhs: StructOffset) -> Option<MemoryLocation> {
+        self.0.checked_add(rhs.0).map(MemoryLocation)
+    }
+}
+
 impl core::ops::Add<StructOffset> for MemoryLocation {
     type Output = Self;
 
@@ -68,11 +82,22 @@ impl From<MemoryLocation> for u64 {
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 /// An offset from the start of the struct
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructOffset(pub u64);
 impl StructOffset {
     pub fn new(offset: u64) -> Self {
         StructOffset(offset)
     }
+
+    /// Add `rhs` to this offset, returning `None` if the result would overflow.
+    pub fn checked_add(self, rhs: StructOffset) -> Option<StructOffset> {
+        self.0.checked_add(rhs.0).map(StructOffset)
+    }
+
+    /// Multiply this offset by `rhs`, returning `None` if the result would overflow.
+    pub fn checked_mul(self, rhs: StructOffset) -> Option<StructOffset> {
+        self.0.checked_mul(rhs.0).map(StructOffset)
+    }
 }
 
 impl core::ops::Add<StructOffset> for StructOffset {
@@ -97,11 +122,13 @@ impl core::fmt::Display for StructOffset {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileName(String);
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 /// An index into a vec
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct EntryIndex(usize);
 
 impl core::fmt::Display for EntryIndex {
@@ -111,6 +138,7 @@ impl core::fmt::Display for EntryIndex {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructureMember {
     name: Option<String>,
     kind: DebugItem,
@@ -132,9 +160,11 @@ impl StructureMember {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenericParameter {
     name: Option<String>,
     kind: DebugItem,
+    value: Option<ConstValue>,
 }
 
 impl GenericParameter {
@@ -145,12 +175,19 @@ impl GenericParameter {
     pub fn kind(&self) -> DebugItem {
         self.kind
     }
+
+    /// For value parameters such as `N` in `[T; N]`, the value of the parameter. This
+    /// is `None` for type parameters.
+    pub fn value(&self) -> Option<&ConstValue> {
+        self.value.as_ref()
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pointer {
     name: Option<String>,
     namespace: String,
-    kind: DebugItem,
+    kind: Option<DebugItem>,
 }
 
 impl Pointer {
@@ -162,7 +199,8 @@ impl Pointer {
         &self.namespace
     }
 
-    pub fn kind(&self) -> DebugItem {
+    /// The type that this pointer points to. This is `None` for `void *`.
+    pub fn kind(&self) -> Option<DebugItem> {
         self.kind
     }
 }
@@ -176,10 +214,119 @@ impl core::fmt::Debug for Pointer {
     }
 }
 
+/// A `typedef`, or a `const`, `volatile` or `restrict` qualifier. These don't change
+/// the layout of the type they wrap.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypeAlias {
+    name: Option<String>,
+    kind: Option<DebugItem>,
+}
+
+impl TypeAlias {
+    /// The name of a typedef. Qualifiers have no name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The type being wrapped. This is `None` for e.g. `const void`.
+    pub fn kind(&self) -> Option<DebugItem> {
+        self.kind
+    }
+}
+
+/// The type of a function, as used by function pointers. This describes the signature
+/// only, not any particular function.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubroutineType {
+    return_type: Option<DebugItem>,
+    parameter_types: Vec<DebugItem>,
+}
+
+impl SubroutineType {
+    /// The type returned by the function, or `None` if it returns nothing.
+    pub fn return_type(&self) -> Option<DebugItem> {
+        self.return_type
+    }
+
+    pub fn parameter_types(&self) -> &[DebugItem] {
+        &self.parameter_types
+    }
+}
+
+/// How the bytes of a [BaseType] are interpreted, decoded from its `DW_AT_encoding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BaseTypeEncoding {
+    Address,
+    Boolean,
+    Float,
+    Signed,
+    SignedChar,
+    Unsigned,
+    UnsignedChar,
+    /// A Unicode code point, such as a Rust `char`.
+    Utf,
+    /// An encoding that has no variant of its own, such as `DW_ATE_complex_float`.
+    Other(gimli::DwAte),
+}
+
+impl From<gimli::DwAte> for BaseTypeEncoding {
+    fn from(encoding: gimli::DwAte) -> Self {
+        match encoding {
+            gimli::constants::DW_ATE_address => BaseTypeEncoding::Address,
+            gimli::constants::DW_ATE_boolean => BaseTypeEncoding::Boolean,
+            gimli::constants::DW_ATE_float => BaseTypeEncoding::Float,
+            gimli::constants::DW_ATE_signed => BaseTypeEncoding::Signed,
+            gimli::constants::DW_ATE_signed_char => BaseTypeEncoding::SignedChar,
+            gimli::constants::DW_ATE_unsigned => BaseTypeEncoding::Unsigned,
+            gimli::constants::DW_ATE_unsigned_char => BaseTypeEncoding::UnsignedChar,
+            gimli::constants::DW_ATE_UTF => BaseTypeEncoding::Utf,
+            other => BaseTypeEncoding::Other(other),
+        }
+    }
+}
+
+impl BaseTypeEncoding {
+    /// Whether this is a signed integer encoding.
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            BaseTypeEncoding::Signed | BaseTypeEncoding::SignedChar
+        )
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaseType {
     name: String,
     namespace: String,
     size: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_encoding",
+            deserialize_with = "deserialize_encoding"
+        )
+    )]
+    encoding: gimli::DwAte,
+}
+
+/// Serialize a `DW_ATE_*` constant as its numeric value, since gimli's constants don't
+/// implement `Serialize`.
+#[cfg(feature = "serde")]
+fn serialize_encoding<S: serde::Serializer>(
+    encoding: &gimli::DwAte,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(encoding.0)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_encoding<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<gimli::DwAte, D::Error> {
+    <u8 as serde::Deserialize>::deserialize(deserializer).map(gimli::DwAte)
 }
 
 impl BaseType {
@@ -194,6 +341,21 @@ impl BaseType {
     pub fn size(&self) -> u64 {
         self.size
     }
+
+    /// The `DW_AT_encoding` of this type, which describes how its bytes are interpreted.
+    pub fn encoding(&self) -> gimli::DwAte {
+        self.encoding
+    }
+
+    /// The encoding of this type, decoded into a [BaseTypeEncoding].
+    pub fn encoding_kind(&self) -> BaseTypeEncoding {
+        self.encoding.into()
+    }
+
+    /// Whether this is a signed integer type.
+    pub fn is_signed(&self) -> bool {
+        self.encoding_kind().is_signed()
+    }
 }
 
 impl core::fmt::Debug for BaseType {
@@ -201,16 +363,21 @@ impl core::fmt::Debug for BaseType {
         f.debug_struct("BaseType")
             .field("name", &self.name)
             .field("size", &self.size)
+            .field("encoding", &self.encoding)
             .finish()
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Union {
     name: String,
     namespace: String,
     members: Vec<StructureMember>,
+    generics: Vec<GenericParameter>,
     size: u64,
+    file: Option<FileName>,
+    line: Option<u64>,
 }
 
 impl Union {
@@ -218,6 +385,8 @@ impl Union {
         &self.name
     }
 
+    /// The module path this type was declared in, joined with `::`, such as `core::option`.
+    /// Types from languages without namespaces, such as C, have an empty namespace.
     pub fn namespace(&self) -> &str {
         &self.namespace
     }
@@ -230,17 +399,42 @@ impl Union {
         &self.members
     }
 
+    pub fn member_count(&self) -> usize {
+        self.members.len()
+    }
+
     pub fn member_named(&self, name: &str) -> Option<&StructureMember> {
         self.members
             .iter()
             .find(|&member| member.name.as_deref() == Some(name))
     }
+
+    pub fn generics(&self) -> &[GenericParameter] {
+        &self.generics
+    }
+
+    pub fn generic_named(&self, name: &str) -> Option<&GenericParameter> {
+        self.generics
+            .iter()
+            .find(|&generic| generic.name.as_deref() == Some(name))
+    }
+
+    /// The source file this type was declared in.
+    pub fn decl_file(&self) -> Option<&str> {
+        self.file.as_ref().map(|v| v.0.as_ref())
+    }
+
+    /// The line of the source file this type was declared on.
+    pub fn decl_line(&self) -> Option<u64> {
+        self.line
+    }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumerationVariant {
     name: String,
-    discriminant: Option<u64>,
+    discriminant: Option<i128>,
     kind: DebugItem,
     offset: StructOffset,
 }
@@ -258,12 +452,13 @@ impl EnumerationVariant {
         self.offset
     }
 
-    pub fn discriminant(&self) -> Option<u64> {
+    pub fn discriminant(&self) -> Option<i128> {
         self.discriminant
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enumeration {
     name: String,
     namespace: String,
@@ -271,6 +466,9 @@ pub struct Enumeration {
     discriminant_kind: DebugItem,
     size: u64,
     variants: Vec<EnumerationVariant>,
+    generics: Vec<GenericParameter>,
+    file: Option<FileName>,
+    line: Option<u64>,
 }
 
 impl Enumeration {
@@ -278,6 +476,8 @@ impl Enumeration {
         &self.name
     }
 
+    /// The module path this type was declared in, joined with `::`, such as `core::option`.
+    /// Types from languages without namespaces, such as C, have an empty namespace.
     pub fn namespace(&self) -> &str {
         &self.namespace
     }
@@ -288,15 +488,25 @@ impl Enumeration {
 
     /// Return the enumeration variant that corresponds to the given discriminant. If the
     /// enum could not be found, return `None`.
-    pub fn variant_with_discriminant(&self, discriminant: usize) -> Option<&EnumerationVariant> {
-        // If we can get the item from the array directly, get it. Otherwise,
-        // return the variant without a discriminant. This is the case for
-        // niche-optimized enums.
-        self.variants.get(discriminant).or_else(|| {
-            self.variants
-                .iter()
-                .find(|&variant| variant.discriminant.is_none())
-        })
+    pub fn variant_with_discriminant(&self, discriminant: i128) -> Option<&EnumerationVariant> {
+        // Without any recorded discriminants, the variants are numbered in order.
+        if self
+            .variants
+            .iter()
+            .all(|variant| variant.discriminant.is_none())
+        {
+            return self.variants.get(usize::try_from(discriminant).ok()?);
+        }
+        // Otherwise, find the variant with a matching discriminant, falling back to
+        // the variant without a discriminant. This is the case for niche-optimized enums.
+        self.variants
+            .iter()
+            .find(|&variant| variant.discriminant == Some(discriminant))
+            .or_else(|| {
+                self.variants
+                    .iter()
+                    .find(|&variant| variant.discriminant.is_none())
+            })
     }
 
     pub fn variant_named(&self, name: &str) -> Option<&EnumerationVariant> {
@@ -307,6 +517,20 @@ impl Enumeration {
         &self.variants
     }
 
+    pub fn variant_count(&self) -> usize {
+        self.variants.len()
+    }
+
+    pub fn generics(&self) -> &[GenericParameter] {
+        &self.generics
+    }
+
+    pub fn generic_named(&self, name: &str) -> Option<&GenericParameter> {
+        self.generics
+            .iter()
+            .find(|&generic| generic.name.as_deref() == Some(name))
+    }
+
     pub fn discriminant_offset(&self) -> StructOffset {
         self.discriminant_offset
     }
@@ -314,18 +538,32 @@ impl Enumeration {
     pub fn discriminant_kind(&self) -> DebugItem {
         self.discriminant_kind
     }
+
+    /// The source file this type was declared in.
+    pub fn decl_file(&self) -> Option<&str> {
+        self.file.as_ref().map(|v| v.0.as_ref())
+    }
+
+    /// The line of the source file this type was declared on.
+    pub fn decl_line(&self) -> Option<u64> {
+        self.line
+    }
 }
 
 #[derive(Clone, Debug)]
 /// Represents either a struct or an enum.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Structure {
     name: String,
     kind: DebugItem,
     members: Vec<StructureMember>,
     generics: Vec<GenericParameter>,
-    size: u64,
+    size: Option<u64>,
     namespace: String,
     containing_type: Option<DebugItem>,
+    declaration: bool,
+    file: Option<FileName>,
+    line: Option<u64>,
 }
 
 impl Structure {
@@ -333,6 +571,8 @@ impl Structure {
         &self.name
     }
 
+    /// The module path this type was declared in, joined with `::`, such as `core::option`.
+    /// Types from languages without namespaces, such as C, have an empty namespace.
     pub fn namespace(&self) -> &str {
         &self.namespace
     }
@@ -345,6 +585,10 @@ impl Structure {
         &self.members
     }
 
+    pub fn member_count(&self) -> usize {
+        self.members.len()
+    }
+
     pub fn member_named(&self, name: &str) -> Option<&StructureMember> {
         self.members
             .iter()
@@ -355,21 +599,48 @@ impl Structure {
         &self.generics
     }
 
-    pub fn size(&self) -> u64 {
+    pub fn generic_named(&self, name: &str) -> Option<&GenericParameter> {
+        self.generics
+            .iter()
+            .find(|&generic| generic.name.as_deref() == Some(name))
+    }
+
+    /// The size of the structure in bytes. This is `None` for declarations.
+    pub fn size(&self) -> Option<u64> {
         self.size
     }
 
     pub fn containing_type(&self) -> Option<DebugItem> {
         self.containing_type
     }
+
+    /// Whether this is only a declaration of a structure that is defined elsewhere,
+    /// usually in another unit. Declarations have no size or members.
+    pub fn is_declaration(&self) -> bool {
+        self.declaration
+    }
+
+    /// The source file this type was declared in.
+    pub fn decl_file(&self) -> Option<&str> {
+        self.file.as_ref().map(|v| v.0.as_ref())
+    }
+
+    /// The line of the source file this type was declared on.
+    pub fn decl_line(&self) -> Option<u64> {
+        self.line
+    }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Array {
     namespace: String,
     kind: DebugItem,
-    lower_bound: u64,
+    /// The `(lower_bound, count)` of each dimension, outermost first.
+    dimensions: Vec<(u64, usize)>,
     count: usize,
+    /// The distance between consecutive elements, if they aren't packed together.
+    byte_stride: Option<u64>,
 }
 
 impl Array {
@@ -381,38 +652,190 @@ impl Array {
         self.kind
     }
 
+    /// The total number of elements in the array, across all dimensions.
     pub fn count(&self) -> usize {
         self.count
     }
 
+    /// The lower bound of the outermost dimension.
     pub fn lower_bound(&self) -> u64 {
-        self.lower_bound
+        self.dimensions
+            .first()
+            .map(|(lower, _)| *lower)
+            .unwrap_or(0)
+    }
+
+    /// The `(lower_bound, count)` of each dimension, outermost first. Elements are
+    /// laid out in row-major order.
+    pub fn dimensions(&self) -> &[(u64, usize)] {
+        &self.dimensions
+    }
+
+    /// The distance in bytes from the start of one element to the start of the next,
+    /// if the compiler gave one with `DW_AT_byte_stride`. Otherwise elements are packed
+    /// and the stride is the size of the element.
+    pub fn byte_stride(&self) -> Option<u64> {
+        self.byte_stride
     }
 }
 
-/// Arrays are stored as an array_type followed by a subrange_type. This contains
-/// just the array_type.
+/// Arrays are stored as an array_type followed by one subrange_type per dimension.
+/// This collects the subranges until the array_type is finished.
 struct PartialArray {
     kind: DebugItem,
+    namespace: String,
+    byte_stride: Option<u64>,
+    subranges: Vec<Subrange>,
+    /// Set if a subrange could not be parsed, in which case the array is dropped.
+    incomplete: bool,
+}
+
+impl PartialArray {
+    fn finish(self) -> Option<Array> {
+        if self.incomplete || self.subranges.is_empty() {
+            return None;
+        }
+        let dimensions: Vec<(u64, usize)> = self
+            .subranges
+            .iter()
+            .map(|subrange| (subrange.lower_bound, subrange.count))
+            .collect();
+        // A stride may also be given for each dimension, and the innermost one is
+        // the distance between elements.
+        let byte_stride = self.byte_stride.or_else(|| {
+            self.subranges
+                .last()
+                .and_then(|subrange| subrange.byte_stride)
+        });
+        Some(Array {
+            namespace: self.namespace,
+            kind: self.kind,
+            count: dimensions
+                .iter()
+                .try_fold(1usize, |total, (_, count)| total.checked_mul(*count))?,
+            dimensions,
+            byte_stride,
+        })
+    }
+}
+
+/// A single row of the line number table
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct LineRow {
+    /// An index into the file table of the unit
+    file: u64,
+    line: u64,
+    address: u64,
 }
 
 /// A tag that describes the contents of the array
 struct Subrange {
     lower_bound: u64,
     count: usize,
+    byte_stride: Option<u64>,
+}
+
+/// A value that the compiler stored directly in the debug information using
+/// `DW_AT_const_value`, rather than placing it in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConstValue {
+    /// A fixed-size value of `size` bytes. Its signedness depends on the type of the variable.
+    Fixed { value: u64, size: u8 },
+    /// A value stored as an unsigned LEB128 number.
+    Unsigned(u64),
+    /// A value stored as a signed LEB128 number.
+    Signed(i64),
+    /// A block of bytes, in the byte order of the target.
+    Bytes(Vec<u8>),
+}
+
+impl ConstValue {
+    pub fn as_u8(&self) -> Option<u8> {
+        self.as_u64()?.try_into().ok()
+    }
+
+    pub fn as_u16(&self) -> Option<u16> {
+        self.as_u64()?.try_into().ok()
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        self.as_u64()?.try_into().ok()
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            ConstValue::Fixed { value, .. } | ConstValue::Unsigned(value) => Some(*value),
+            ConstValue::Signed(value) => (*value).try_into().ok(),
+            ConstValue::Bytes(_) => None,
+        }
+    }
+
+    /// Return the value as a signed number. Fixed-size values are sign-extended from
+    /// their stored size.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            ConstValue::Fixed { value, size } => {
+                let shift = 64u32.checked_sub(u32::from(*size) * 8)?;
+                Some(((*value << shift) as i64) >> shift)
+            }
+            ConstValue::Unsigned(value) => (*value).try_into().ok(),
+            ConstValue::Signed(value) => Some(*value),
+            ConstValue::Bytes(_) => None,
+        }
+    }
+
+    /// The raw bytes of a block value.
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            ConstValue::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Variable {
     name: String,
     namespace: String,
     kind: DebugItem,
-    location: MemoryLocation,
+    location: Option<MemoryLocation>,
+    tls_offset: Option<u64>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_location_list",
+            deserialize_with = "deserialize_location_list"
+        )
+    )]
+    location_list: Option<gimli::LocationListsOffset>,
+    const_value: Option<ConstValue>,
     linkage_name: Option<String>,
     line: Option<u64>,
     file: Option<FileName>,
 }
 
+/// Serialize a location list offset as a plain number.
+#[cfg(feature = "serde")]
+fn serialize_location_list<S: serde::Serializer>(
+    offset: &Option<gimli::LocationListsOffset>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match offset {
+        Some(offset) => serializer.serialize_some(&offset.0),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_location_list<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<gimli::LocationListsOffset>, D::Error> {
+    <Option<usize> as serde::Deserialize>::deserialize(deserializer)
+        .map(|offset| offset.map(gimli::LocationListsOffset))
+}
+
 impl Variable {
     pub fn name(&self) -> &str {
         &self.name
@@ -422,14 +845,63 @@ impl Variable {
         &self.namespace
     }
 
+    /// The symbol name of this variable, which is usually mangled.
+    pub fn linkage_name(&self) -> Option<&str> {
+        self.linkage_name.as_deref()
+    }
+
+    /// The demangled form of the linkage name, such as `my_crate::COUNTER`, without the
+    /// trailing hash. Falls back to the plain name if there is no linkage name.
+    pub fn demangled_name(&self) -> String {
+        let name = self.linkage_name.as_deref().unwrap_or(&self.name);
+        format!("{:#}", rustc_demangle::demangle(name))
+    }
+
     pub fn kind(&self) -> DebugItem {
         self.kind
     }
 
-    pub fn location(&self) -> MemoryLocation {
+    /// The address of this variable. This is `None` if the variable is described by a
+    /// location list that has no entry with a plain memory address.
+    pub fn location(&self) -> Option<MemoryLocation> {
         self.location
     }
 
+    /// For thread-local variables, the offset of this variable within the TLS block of
+    /// its module. The address for a given thread depends on that thread's TLS base.
+    pub fn tls_offset(&self) -> Option<u64> {
+        self.tls_offset
+    }
+
+    /// For variables whose location depends on the program counter, the offset of their
+    /// location list. A caller that knows the current program counter can use this to
+    /// find where the variable lives.
+    pub fn location_list_offset(&self) -> Option<gimli::LocationListsOffset> {
+        self.location_list
+    }
+
+    /// Summarize where this variable can be found.
+    pub fn variable_location(&self) -> crate::extract::VariableLocation {
+        use crate::extract::VariableLocation;
+        if let Some(location) = self.location {
+            VariableLocation::Address(location.0)
+        } else if let Some(offset) = self.tls_offset {
+            VariableLocation::TlsOffset(offset)
+        } else if let Some(offset) = self.location_list {
+            VariableLocation::LocationList(offset)
+        } else if self.const_value.is_some() {
+            VariableLocation::Value
+        } else {
+            VariableLocation::Unavailable
+        }
+    }
+
+    /// The value of this variable, if the compiler stored it in the debug information.
+    /// Such variables may have no location in memory at all.
+    pub fn const_value(&self) -> Option<&ConstValue> {
+        self.const_value.as_ref()
+    }
+
     pub fn file(&self) -> Option<&str> {
         self.file.as_ref().map(|v| v.0.as_ref())
     }
@@ -439,6 +911,7 @@ impl Variable {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolCache {
     /// A list of all variables in this section
     variables: Vec<Variable>,
@@ -461,6 +934,21 @@ pub struct SymbolCache {
     /// A list of all unions in this section
     unions: Vec<Union>,
 
+    /// A list of all subroutine types in this section
+    subroutine_types: Vec<SubroutineType>,
+
+    /// A list of all typedefs and type qualifiers in this section
+    type_aliases: Vec<TypeAlias>,
+
+    /// The source files referred to by the line table, by file index
+    line_files: HashMap<u64, FileName>,
+
+    /// The start of every statement in the line table
+    lines: Vec<LineRow>,
+
+    /// The ranges of code addresses covered by this unit
+    address_ranges: Vec<core::ops::Range<u64>>,
+
     /// Pointers to variables by the variable's exported name
     variable_names: HashMap<String, Vec<EntryIndex>>,
 
@@ -468,32 +956,122 @@ pub struct SymbolCache {
     demangled_variable_names: HashMap<String, Vec<EntryIndex>>,
 
     /// Pointers from the variable's address to the variable
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     variable_address: HashMap<DebugItem, EntryIndex>,
 
     /// Pointers from the structure's offset to the structure
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     structure_address: HashMap<DebugItem, EntryIndex>,
 
     /// Pointers from the structure's offset to the enumeration
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     enumeration_address: HashMap<DebugItem, EntryIndex>,
 
     /// Pointers from the array's offset to the array
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     array_address: HashMap<DebugItem, EntryIndex>,
 
     /// Pointers from the pointer's offset to the pointer
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     pointer_address: HashMap<DebugItem, EntryIndex>,
 
     /// Pointers from the base type's offset to the base type
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     base_type_address: HashMap<DebugItem, EntryIndex>,
 
     /// Pointers from the union's offset to the union
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
     union_address: HashMap<DebugItem, EntryIndex>,
+
+    /// Pointers from the subroutine type's offset to the subroutine type
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
+    subroutine_type_address: HashMap<DebugItem, EntryIndex>,
+
+    /// Pointers from the typedef or qualifier's offset to the type alias
+    #[cfg_attr(feature = "serde", serde(with = "map_entries"))]
+    type_alias_address: HashMap<DebugItem, EntryIndex>,
+}
+
+/// Serialize a map whose keys aren't strings as a list of `(key, value)` pairs, since
+/// many formats such as JSON only allow string keys.
+#[cfg(feature = "serde")]
+mod map_entries {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: serde::Serialize,
+        V: serde::Serialize,
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: serde::Deserialize<'de> + Eq + Hash,
+        V: serde::Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        let entries: Vec<(K, V)> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+/// Serialize the offset of a unit as a plain number.
+#[cfg(feature = "serde")]
+mod unit_section_offset {
+    use gimli::UnitSectionOffset;
+
+    pub fn serialize<S: serde::Serializer>(
+        offset: &UnitSectionOffset,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(offset.0 as u64)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UnitSectionOffset, D::Error> {
+        <usize as serde::Deserialize>::deserialize(deserializer).map(UnitSectionOffset)
+    }
+}
+
+/// Serialize the section that a unit came from by its name. Units are only ever found
+/// in `.debug_info` or `.debug_types`.
+#[cfg(feature = "serde")]
+mod section_id {
+    use gimli::SectionId;
+
+    pub fn serialize<S: serde::Serializer>(
+        section: &SectionId,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(section.name())
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SectionId, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        [SectionId::DebugInfo, SectionId::DebugTypes]
+            .into_iter()
+            .find(|section| section.name() == name)
+            .ok_or_else(|| serde::de::Error::custom(format!("unexpected unit section {name}")))
+    }
 }
 
 /// A struct containing information about a single compilation unit.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitInfo {
     cache: SymbolCache,
+    #[cfg_attr(feature = "serde", serde(with = "unit_section_offset"))]
     pub offset: UnitSectionOffset,
+    #[cfg_attr(feature = "serde", serde(with = "section_id"))]
     pub(crate) section: SectionId,
+    /// The size of a target address in bytes
+    pub(crate) address_size: u8,
 }
 
 impl UnitInfo {
@@ -506,6 +1084,8 @@ impl UnitInfo {
             .chain(self.cache.pointer_address.keys())
             .chain(self.cache.structure_address.keys())
             .chain(self.cache.union_address.keys())
+            .chain(self.cache.subroutine_type_address.keys())
+            .chain(self.cache.type_alias_address.keys())
             .chain(self.cache.variable_address.keys())
             .copied()
             .collect()
@@ -514,6 +1094,7 @@ impl UnitInfo {
     pub fn new<ENDIAN: Endianity>(
         unit: gimli::Unit<GimliReader<ENDIAN>>,
         dwarf: &gimli::Dwarf<GimliReader<ENDIAN>>,
+        options: &DebugInfoOptions,
     ) -> Option<Self> {
         let unit_ref = unit.unit_ref(dwarf);
         let mut variables = vec![];
@@ -523,33 +1104,48 @@ impl UnitInfo {
         let mut pointers = vec![];
         let mut base_types = vec![];
         let mut unions: Vec<Union> = vec![];
+        let mut subroutine_types: Vec<SubroutineType> = vec![];
+        let mut type_aliases = vec![];
         let mut variable_names: HashMap<String, Vec<EntryIndex>> = HashMap::new();
         let mut demangled_variable_names: HashMap<String, Vec<EntryIndex>> = HashMap::new();
 
         let mut variable_address = HashMap::new();
-        let mut structure_address = HashMap::new();
-        let mut enumeration_address = HashMap::new();
+        let mut structure_address: HashMap<DebugItem, EntryIndex> = HashMap::new();
+        let mut enumeration_address: HashMap<DebugItem, EntryIndex> = HashMap::new();
         let mut array_address = HashMap::new();
         let mut pointer_address = HashMap::new();
         let mut base_type_address = HashMap::new();
-        let mut union_address = HashMap::new();
+        let mut union_address: HashMap<DebugItem, EntryIndex> = HashMap::new();
+        let mut subroutine_type_address: HashMap<DebugItem, EntryIndex> = HashMap::new();
+        let mut type_alias_address = HashMap::new();
 
         let mut array_in_progress: Option<(PartialArray, DebugItem)> = None;
         let mut tag_parent_list = vec![];
+        let mut item_parent_list: Vec<Option<DebugItem>> = vec![];
         let mut last_structure_address: Option<DebugItem> = None;
 
         let mut parent_namespace = vec![];
+        // The namespace of each variable declaration, for definitions that complete a
+        // declaration from elsewhere with `DW_AT_specification`.
+        let mut declaration_namespaces: HashMap<gimli::UnitOffset, Vec<String>> = HashMap::new();
 
         let mut entries = unit_ref.entries();
         let mut depth = 0usize;
         while let Ok(Some(abbrev)) = entries.next_dfs() {
             let depth_delta = abbrev.depth - (depth as isize);
+            // A C++ `class` is laid out exactly like a `struct`, so it's parsed as one.
+            let tag = match abbrev.tag() {
+                gimli::constants::DW_TAG_class_type => gimli::constants::DW_TAG_structure_type,
+                tag => tag,
+            };
             if depth_delta < 0 {
                 if depth_delta.unsigned_abs() > depth {
-                    panic!(
+                    log::error!(
                         "Depth went negative! Delta: {}  depth: {}",
-                        depth_delta, depth
+                        depth_delta,
+                        depth
                     );
+                    break;
                 }
                 depth = depth.saturating_sub(depth_delta.unsigned_abs());
             } else {
@@ -568,17 +1164,49 @@ impl UnitInfo {
                 tag_parent_list.push(gimli::constants::DW_TAG_null);
             }
             tag_parent_list.pop();
-            tag_parent_list.push(abbrev.tag());
+            tag_parent_list.push(tag);
 
             let parent_tag = *tag_parent_list
                 .get(tag_parent_list.len().saturating_sub(2))
                 .unwrap_or(&gimli::constants::DW_TAG_null);
 
-            match abbrev.tag() {
+            // Keep track of the DIE that encloses this one, for children such as generic
+            // parameters that may appear after other nested types.
+            item_parent_list.resize(depth, None);
+            let parent_item = item_parent_list.last().copied().flatten();
+            item_parent_list.push(DebugItem::from_unit_offset(abbrev.offset(), unit_ref));
+
+            // An array is complete once we reach something other than one of its subranges.
+            if !(tag == gimli::constants::DW_TAG_subrange_type
+                && parent_tag == gimli::constants::DW_TAG_array_type)
+                && let Some((partial, offset)) = array_in_progress.take()
+                && let Some(array) = partial.finish()
+            {
+                insert_entry(&mut array_address, offset, arrays.len());
+                arrays.push(array);
+            }
+
+            // Remember where each declaration is, for definitions that complete it later.
+            // Static members are declared with `DW_TAG_member` before DWARF 5.
+            if matches!(
+                abbrev.tag(),
+                gimli::constants::DW_TAG_variable | gimli::constants::DW_TAG_member
+            ) && abbrev
+                .attr_value(gimli::constants::DW_AT_declaration)
+                .is_some()
+            {
+                declaration_namespaces.insert(abbrev.offset(), parent_namespace.clone());
+            }
+
+            match tag {
                 gimli::constants::DW_TAG_variable => {
-                    let Some(variable) =
-                        parse_variable(abbrev.attrs(), &parent_namespace, unit_ref)
-                    else {
+                    let Some(variable) = parse_variable(
+                        abbrev.attrs(),
+                        &parent_namespace,
+                        &declaration_namespaces,
+                        unit_ref,
+                        options,
+                    ) else {
                         continue;
                     };
 
@@ -631,11 +1259,7 @@ impl UnitInfo {
                                 .push(EntryIndex(variables.len()));
                         }
                     }
-                    assert!(
-                        variable_address
-                            .insert(offset, EntryIndex(variables.len()),)
-                            .is_none()
-                    );
+                    insert_entry(&mut variable_address, offset, variables.len());
                     variables.push(variable);
                 }
 
@@ -648,20 +1272,31 @@ impl UnitInfo {
                         continue;
                     };
                     // Remove the struct form the address and add it to the enumeration list
-                    let last_structure_address = last_structure_address.take().unwrap();
-                    assert!(structure_address.remove(&last_structure_address).is_some());
+                    let Some(last_structure_address) = last_structure_address.take() else {
+                        log::error!("Variant part without a structure address");
+                        continue;
+                    };
+                    // Only forget the address if it refers to the structure that was just removed.
+                    if structure_address.get(&last_structure_address)
+                        == Some(&EntryIndex(structures.len()))
+                    {
+                        structure_address.remove(&last_structure_address);
+                    }
                     enumeration_address
                         .insert(last_structure_address, EntryIndex(enumerations.len()));
                     // TODO: Parse `discr` type. For now we just assume it's the first one.
                     enumerations.push(Enumeration {
                         name: structure.name,
-                        namespace: parent_namespace.join("::"),
+                        namespace: structure.namespace,
                         discriminant_kind: DebugItem::from_debug_info_offset(
                             gimli::DebugInfoOffset(0),
                         ),
                         discriminant_offset: StructOffset(0),
-                        size: structure.size,
+                        size: structure.size.unwrap_or(0),
                         variants: vec![],
+                        generics: structure.generics,
+                        file: structure.file,
+                        line: structure.line,
                     });
                 }
 
@@ -735,20 +1370,47 @@ impl UnitInfo {
                 gimli::constants::DW_TAG_member
                     if parent_tag == gimli::constants::DW_TAG_structure_type =>
                 {
+                    // Anonymous structures aren't kept, so their members must not be
+                    // given to whichever structure came before them.
                     if let Some(member) = parse_structure_member(abbrev.attrs(), unit_ref)
-                        && let Some(last) = structures.last_mut()
+                        && let Some(structure) = parent_item
+                            .and_then(|parent_item| structure_address.get(&parent_item))
+                            .and_then(|index| structures.get_mut(index.0))
                     {
-                        last.members.push(member);
+                        structure.members.push(member);
                     }
                 }
 
                 gimli::constants::DW_TAG_template_type_parameter
-                    if parent_tag == gimli::constants::DW_TAG_structure_type =>
+                | gimli::constants::DW_TAG_template_value_parameter
+                    if matches!(
+                        parent_tag,
+                        gimli::constants::DW_TAG_structure_type
+                            | gimli::constants::DW_TAG_union_type
+                    ) =>
                 {
-                    if let Some(generic) = parse_generic_parameter(abbrev.attrs(), unit_ref)
-                        && let Some(last) = structures.last_mut()
+                    let Some(generic) = parse_generic_parameter(abbrev.attrs(), unit_ref) else {
+                        continue;
+                    };
+                    let Some(parent_item) = parent_item else {
+                        continue;
+                    };
+                    // The structure may have become an enumeration by now.
+                    if let Some(structure) = structure_address
+                        .get(&parent_item)
+                        .and_then(|index| structures.get_mut(index.0))
                     {
-                        last.generics.push(generic);
+                        structure.generics.push(generic);
+                    } else if let Some(enumeration) = enumeration_address
+                        .get(&parent_item)
+                        .and_then(|index| enumerations.get_mut(index.0))
+                    {
+                        enumeration.generics.push(generic);
+                    } else if let Some(union) = union_address
+                        .get(&parent_item)
+                        .and_then(|index| unions.get_mut(index.0))
+                    {
+                        union.generics.push(generic);
                     }
                 }
 
@@ -757,9 +1419,11 @@ impl UnitInfo {
                     if parent_tag == gimli::constants::DW_TAG_union_type =>
                 {
                     if let Some(member) = parse_structure_member(abbrev.attrs(), unit_ref)
-                        && let Some(last) = unions.last_mut()
+                        && let Some(union) = parent_item
+                            .and_then(|parent_item| union_address.get(&parent_item))
+                            .and_then(|index| unions.get_mut(index.0))
                     {
-                        last.members.push(member);
+                        union.members.push(member);
                     }
                 }
 
@@ -773,11 +1437,7 @@ impl UnitInfo {
                     else {
                         continue;
                     };
-                    assert!(
-                        structure_address
-                            .insert(offset, EntryIndex(structures.len()))
-                            .is_none()
-                    );
+                    insert_entry(&mut structure_address, offset, structures.len());
                     last_structure_address = Some(offset);
                     structures.push(structure);
                 }
@@ -791,11 +1451,7 @@ impl UnitInfo {
                     else {
                         continue;
                     };
-                    assert!(
-                        union_address
-                            .insert(offset, EntryIndex(unions.len()))
-                            .is_none()
-                    );
+                    insert_entry(&mut union_address, offset, unions.len());
                     last_structure_address = Some(offset);
                     unions.push(new_union);
                 }
@@ -805,32 +1461,21 @@ impl UnitInfo {
                     else {
                         continue;
                     };
-                    array_in_progress = parse_array(abbrev.attrs(), unit_ref).map(|v| (v, offset));
+                    array_in_progress = parse_array(abbrev.attrs(), &parent_namespace, unit_ref)
+                        .map(|v| (v, offset));
                 }
 
                 gimli::constants::DW_TAG_subrange_type
                     if parent_tag == gimli::constants::DW_TAG_array_type =>
                 {
-                    let Some(subrange) = parse_subrange(abbrev.attrs()) else {
+                    let Some((array_in_progress, _)) = array_in_progress.as_mut() else {
+                        log::error!("Got a subrange without an array in progress");
                         continue;
                     };
-                    let Some((array_in_progress, offset)) = array_in_progress.take() else {
-                        panic!(
-                            "Got a subrange without an array in progress! Are there two subtypes? Or no array type?"
-                        );
-                    };
-                    let array = Array {
-                        kind: array_in_progress.kind,
-                        namespace: parent_namespace.join("::"),
-                        lower_bound: subrange.lower_bound,
-                        count: subrange.count,
-                    };
-                    assert!(
-                        array_address
-                            .insert(offset, EntryIndex(arrays.len()))
-                            .is_none()
-                    );
-                    arrays.push(array);
+                    match parse_subrange(abbrev.attrs()) {
+                        Some(subrange) => array_in_progress.subranges.push(subrange),
+                        None => array_in_progress.incomplete = true,
+                    }
                 }
 
                 gimli::constants::DW_TAG_pointer_type => {
@@ -841,17 +1486,50 @@ impl UnitInfo {
                     let Some(offset) = abbrev.offset().to_debug_info_offset(&unit.header) else {
                         continue;
                     };
-                    assert!(
-                        pointer_address
-                            .insert(
-                                DebugItem::from_debug_info_offset(offset),
-                                EntryIndex(pointers.len())
-                            )
-                            .is_none()
+                    insert_entry(
+                        &mut pointer_address,
+                        DebugItem::from_debug_info_offset(offset),
+                        pointers.len(),
                     );
                     pointers.push(pointer);
                 }
 
+                gimli::constants::DW_TAG_subroutine_type => {
+                    let Some(offset) = DebugItem::from_unit_offset(abbrev.offset(), unit_ref)
+                    else {
+                        continue;
+                    };
+                    let subroutine_type = parse_subroutine_type(abbrev.attrs(), unit_ref);
+                    insert_entry(&mut subroutine_type_address, offset, subroutine_types.len());
+                    subroutine_types.push(subroutine_type);
+                }
+
+                gimli::constants::DW_TAG_typedef
+                | gimli::constants::DW_TAG_const_type
+                | gimli::constants::DW_TAG_volatile_type
+                | gimli::constants::DW_TAG_restrict_type => {
+                    let Some(offset) = DebugItem::from_unit_offset(abbrev.offset(), unit_ref)
+                    else {
+                        continue;
+                    };
+                    let type_alias = parse_type_alias(abbrev.attrs(), unit_ref);
+                    insert_entry(&mut type_alias_address, offset, type_aliases.len());
+                    type_aliases.push(type_alias);
+                }
+
+                gimli::constants::DW_TAG_formal_parameter
+                    if parent_tag == gimli::constants::DW_TAG_subroutine_type =>
+                {
+                    if let Some(kind) = abbrev.attr(gimli::constants::DW_AT_type)
+                        && let Some(kind) = parse_type(kind, unit_ref)
+                        && let Some(subroutine_type) = parent_item
+                            .and_then(|parent_item| subroutine_type_address.get(&parent_item))
+                            .and_then(|index| subroutine_types.get_mut(index.0))
+                    {
+                        subroutine_type.parameter_types.push(kind);
+                    }
+                }
+
                 gimli::constants::DW_TAG_base_type => {
                     let Some(base_type) =
                         parse_base_type(abbrev.attrs(), &parent_namespace, unit_ref)
@@ -861,13 +1539,10 @@ impl UnitInfo {
                     let Some(offset) = abbrev.offset().to_debug_info_offset(&unit.header) else {
                         continue;
                     };
-                    assert!(
-                        base_type_address
-                            .insert(
-                                DebugItem::from_debug_info_offset(offset),
-                                EntryIndex(base_types.len())
-                            )
-                            .is_none()
+                    insert_entry(
+                        &mut base_type_address,
+                        DebugItem::from_debug_info_offset(offset),
+                        base_types.len(),
                     );
                     base_types.push(base_type);
                 }
@@ -887,6 +1562,37 @@ impl UnitInfo {
             }
         }
 
+        if let Some((partial, offset)) = array_in_progress.take()
+            && let Some(array) = partial.finish()
+        {
+            array_address.insert(offset, EntryIndex(arrays.len()));
+            arrays.push(array);
+        }
+
+        // Discriminants are recorded without knowing the type of the discriminant, so
+        // fix up their sign now that all types in this unit are known.
+        for enumeration in &mut enumerations {
+            let Some(base_type) = base_type_address
+                .get(&enumeration.discriminant_kind)
+                .and_then(|index| base_types.get(index.0))
+            else {
+                continue;
+            };
+            for variant in &mut enumeration.variants {
+                variant.discriminant = variant.discriminant.map(|discriminant| {
+                    normalize_discriminant(discriminant, base_type.size, base_type.is_signed())
+                });
+            }
+        }
+
+        let (line_files, lines) = if options.load_line_info {
+            parse_line_rows(unit_ref)
+        } else {
+            Default::default()
+        };
+
+        let address_ranges = parse_unit_ranges(unit_ref);
+
         let cache = SymbolCache {
             variables,
             structures,
@@ -895,6 +1601,11 @@ impl UnitInfo {
             pointers,
             base_types,
             unions,
+            subroutine_types,
+            type_aliases,
+            line_files,
+            lines,
+            address_ranges,
             variable_names,
             demangled_variable_names,
             variable_address,
@@ -904,12 +1615,15 @@ impl UnitInfo {
             pointer_address,
             base_type_address,
             union_address,
+            subroutine_type_address,
+            type_alias_address,
         };
 
         Some(Self {
             cache,
             offset: unit.header.offset(),
             section: unit.header.section(),
+            address_size: unit.header.address_size(),
         })
     }
 
@@ -959,6 +1673,257 @@ impl UnitInfo {
         results
     }
 
+    /// Return every variable with a demangled name that satisfies `matches`, along with
+    /// the name that matched. Variables are returned in the order they appear in the unit.
+    pub fn variables_matching_demangled_name(
+        &self,
+        matches: impl Fn(&str) -> bool,
+    ) -> Vec<(&str, &Variable)> {
+        let mut found: BTreeMap<usize, &str> = BTreeMap::new();
+        for (name, entries) in &self.cache.demangled_variable_names {
+            if !matches(name) {
+                continue;
+            }
+            for entry in entries {
+                found
+                    .entry(entry.0)
+                    .and_modify(|existing| {
+                        if name.as_str() < *existing {
+                            *existing = name;
+                        }
+                    })
+                    .or_insert(name);
+            }
+        }
+        found
+            .into_iter()
+            .filter_map(|(index, name)| Some((name, self.cache.variables.get(index)?)))
+            .collect()
+    }
+
+    pub(crate) fn structures(&self) -> &[Structure] {
+        &self.cache.structures
+    }
+
+    /// All structures in this unit along with their items, in the order they appear in
+    /// the debug information.
+    pub(crate) fn structure_items(&self) -> Vec<(DebugItem, &Structure)> {
+        items_in_order(&self.cache.structure_address, &self.cache.structures)
+    }
+
+    /// All enumerations in this unit along with their items, in the order they appear in
+    /// the debug information.
+    pub(crate) fn enumeration_items(&self) -> Vec<(DebugItem, &Enumeration)> {
+        items_in_order(&self.cache.enumeration_address, &self.cache.enumerations)
+    }
+
+    /// All unions in this unit along with their items, in the order they appear in
+    /// the debug information.
+    pub(crate) fn union_items(&self) -> Vec<(DebugItem, &Union)> {
+        items_in_order(&self.cache.union_address, &self.cache.unions)
+    }
+
+    /// All of the types and variables in this unit, keyed by their offset within the
+    /// debug information.
+    #[cfg(feature = "serde")]
+    pub(crate) fn serialized(&self) -> crate::SerializedUnit<'_> {
+        fn keyed<T>(items: Vec<(DebugItem, &T)>) -> BTreeMap<u64, &T> {
+            items
+                .into_iter()
+                .map(|(item, value)| (item.offset, value))
+                .collect()
+        }
+        let cache = &self.cache;
+        crate::SerializedUnit {
+            variables: keyed(items_in_order(&cache.variable_address, &cache.variables)),
+            structures: keyed(self.structure_items()),
+            enumerations: keyed(self.enumeration_items()),
+            unions: keyed(self.union_items()),
+            arrays: keyed(items_in_order(&cache.array_address, &cache.arrays)),
+            pointers: keyed(items_in_order(&cache.pointer_address, &cache.pointers)),
+            base_types: keyed(items_in_order(&cache.base_type_address, &cache.base_types)),
+        }
+    }
+
+    /// All static variables in this unit, in the order they appear in the debug information.
+    pub fn variables(&self) -> &[Variable] {
+        &self.cache.variables
+    }
+
+    /// Map the address of each vtable in this unit to the concrete type that it belongs to.
+    /// rustc describes vtables as statics whose type has a `DW_AT_containing_type`.
+    pub(crate) fn vtables(&self) -> impl Iterator<Item = (MemoryLocation, DebugItem)> + '_ {
+        self.cache.variables.iter().filter_map(|variable| {
+            let location = variable.location?;
+            let concrete = self.structure_from_item(variable.kind)?.containing_type?;
+            Some((location, concrete))
+        })
+    }
+
+    /// Return the address of every statement that starts on `line` of a source file whose
+    /// path ends with `file_suffix`. The suffix is compared by whole path components.
+    pub fn addresses_for_line<'a>(
+        &'a self,
+        file_suffix: &'a str,
+        line: u64,
+    ) -> impl Iterator<Item = u64> + 'a {
+        self.cache
+            .lines
+            .iter()
+            .filter(move |row| {
+                row.line == line
+                    && self
+                        .cache
+                        .line_files
+                        .get(&row.file)
+                        .is_some_and(|file| std::path::Path::new(&file.0).ends_with(file_suffix))
+            })
+            .map(|row| row.address)
+    }
+
+    /// The ranges of code addresses that this unit was compiled to, taken from
+    /// `DW_AT_low_pc`/`DW_AT_high_pc` or `DW_AT_ranges`.
+    pub fn address_ranges(&self) -> &[core::ops::Range<u64>] {
+        &self.cache.address_ranges
+    }
+
+    /// Whether the code at `address` belongs to this unit.
+    pub fn contains_address(&self, address: u64) -> bool {
+        self.cache
+            .address_ranges
+            .iter()
+            .any(|range| range.contains(&address))
+    }
+
+    /// Count the items of each kind in this unit.
+    pub(crate) fn statistics(&self) -> crate::DebugInfoStatistics {
+        crate::DebugInfoStatistics {
+            units: 1,
+            variables: self.cache.variables.len(),
+            structures: self.cache.structures.len(),
+            enumerations: self.cache.enumerations.len(),
+            unions: self.cache.unions.len(),
+            arrays: self.cache.arrays.len(),
+            pointers: self.cache.pointers.len(),
+            base_types: self.cache.base_types.len(),
+            subroutine_types: self.cache.subroutine_types.len(),
+        }
+    }
+
+    /// Add `base` to every [DebugItem] in this unit. This is used when combining units
+    /// from several Elf files, whose offsets would otherwise collide.
+    pub(crate) fn rebase(&mut self, base: u64) {
+        fn rebase_keys<V>(map: &mut HashMap<DebugItem, V>, base: u64) {
+            *map = map
+                .drain()
+                .map(|(mut item, value)| {
+                    item.rebase(base);
+                    (item, value)
+                })
+                .collect();
+        }
+        fn rebase_members(members: &mut [StructureMember], base: u64) {
+            for member in members {
+                member.kind.rebase(base);
+            }
+        }
+        fn rebase_generics(generics: &mut [GenericParameter], base: u64) {
+            for generic in generics {
+                generic.kind.rebase(base);
+            }
+        }
+
+        let cache = &mut self.cache;
+        for variable in &mut cache.variables {
+            variable.kind.rebase(base);
+        }
+        for structure in &mut cache.structures {
+            structure.kind.rebase(base);
+            if let Some(containing_type) = &mut structure.containing_type {
+                containing_type.rebase(base);
+            }
+            rebase_members(&mut structure.members, base);
+            rebase_generics(&mut structure.generics, base);
+        }
+        for enumeration in &mut cache.enumerations {
+            enumeration.discriminant_kind.rebase(base);
+            for variant in &mut enumeration.variants {
+                variant.kind.rebase(base);
+            }
+            rebase_generics(&mut enumeration.generics, base);
+        }
+        for array in &mut cache.arrays {
+            array.kind.rebase(base);
+        }
+        for pointer in &mut cache.pointers {
+            if let Some(kind) = &mut pointer.kind {
+                kind.rebase(base);
+            }
+        }
+        for union in &mut cache.unions {
+            rebase_members(&mut union.members, base);
+            rebase_generics(&mut union.generics, base);
+        }
+        for subroutine_type in &mut cache.subroutine_types {
+            if let Some(return_type) = &mut subroutine_type.return_type {
+                return_type.rebase(base);
+            }
+            for parameter_type in &mut subroutine_type.parameter_types {
+                parameter_type.rebase(base);
+            }
+        }
+        for type_alias in &mut cache.type_aliases {
+            if let Some(kind) = &mut type_alias.kind {
+                kind.rebase(base);
+            }
+        }
+        rebase_keys(&mut cache.variable_address, base);
+        rebase_keys(&mut cache.structure_address, base);
+        rebase_keys(&mut cache.enumeration_address, base);
+        rebase_keys(&mut cache.array_address, base);
+        rebase_keys(&mut cache.pointer_address, base);
+        rebase_keys(&mut cache.base_type_address, base);
+        rebase_keys(&mut cache.union_address, base);
+        rebase_keys(&mut cache.subroutine_type_address, base);
+        rebase_keys(&mut cache.type_alias_address, base);
+    }
+
+    /// Drop every variable for which `keep` returns `false`.
+    pub(crate) fn retain_variables<F: FnMut(&Variable) -> bool>(&mut self, mut keep: F) {
+        let cache = &mut self.cache;
+        let mut remap = Vec::with_capacity(cache.variables.len());
+        let mut next = 0;
+        for variable in &cache.variables {
+            if keep(variable) {
+                remap.push(Some(EntryIndex(next)));
+                next += 1;
+            } else {
+                remap.push(None);
+            }
+        }
+        let mut kept = remap.iter().map(Option::is_some);
+        cache.variables.retain(|_| kept.next().unwrap_or(false));
+
+        for entries in cache
+            .variable_names
+            .values_mut()
+            .chain(cache.demangled_variable_names.values_mut())
+        {
+            *entries = entries.iter().filter_map(|entry| remap[entry.0]).collect();
+        }
+        cache
+            .variable_names
+            .retain(|_, entries| !entries.is_empty());
+        cache
+            .demangled_variable_names
+            .retain(|_, entries| !entries.is_empty());
+        cache.variable_address = cache
+            .variable_address
+            .drain()
+            .filter_map(|(item, entry)| remap[entry.0].map(|entry| (item, entry)))
+            .collect();
+    }
+
     pub fn variable_from_item(&self, location: DebugItem) -> Option<&Variable> {
         self.cache
             .variable_address
@@ -991,12 +1956,7 @@ impl UnitInfo {
         self.cache
             .base_type_address
             .iter()
-            .find(|(_, index)| {
-                self.cache
-                    .base_types
-                    .get(index.0)
-                    .is_some_and(|base_type| predicate(base_type))
-            })
+            .find(|(_, index)| self.cache.base_types.get(index.0).is_some_and(&predicate))
             .map(|(item, _)| *item)
     }
 
@@ -1042,6 +2002,26 @@ impl UnitInfo {
             .and_then(|addr| self.cache.unions.get(addr.0))
     }
 
+    pub fn subroutine_type_from_item(&self, location: DebugItem) -> Option<&SubroutineType> {
+        self.cache
+            .subroutine_type_address
+            .get(&location)
+            .and_then(|addr| self.cache.subroutine_types.get(addr.0))
+    }
+
+    pub fn type_alias_from_item(&self, location: DebugItem) -> Option<&TypeAlias> {
+        self.cache
+            .type_alias_address
+            .get(&location)
+            .and_then(|addr| self.cache.type_aliases.get(addr.0))
+    }
+
+    /// Whether `location` is a function type that takes no parameters and returns nothing.
+    fn is_plain_subroutine_type(&self, location: DebugItem) -> bool {
+        self.subroutine_type_from_item(location)
+            .is_some_and(|val| val.return_type.is_none() && val.parameter_types.is_empty())
+    }
+
     pub fn size_from_item(&self, location: DebugItem) -> Option<StructOffset> {
         if let Some(val) = self
             .cache
@@ -1049,7 +2029,7 @@ impl UnitInfo {
             .get(&location)
             .and_then(|addr| self.cache.structures.get(addr.0))
         {
-            Some(StructOffset(val.size))
+            val.size.map(StructOffset)
         } else if let Some(val) = self
             .cache
             .enumeration_address
@@ -1113,7 +2093,13 @@ impl UnitInfo {
             .get(&location)
             .and_then(|addr| self.cache.pointers.get(addr.0))
         {
-            val.name()
+            val.name().or_else(|| match val.kind() {
+                None => Some("*const void"),
+                Some(kind) if self.is_plain_subroutine_type(kind) => Some("fn()"),
+                Some(_) => None,
+            })
+        } else if self.is_plain_subroutine_type(location) {
+            Some("fn()")
         } else if let Some(val) = self
             .cache
             .base_type_address
@@ -1138,19 +2124,75 @@ impl UnitInfo {
     }
 }
 
+/// Pair each entry of `list` with its item from `addresses`, in the order of `list`.
+fn items_in_order<'a, T>(
+    addresses: &HashMap<DebugItem, EntryIndex>,
+    list: &'a [T],
+) -> Vec<(DebugItem, &'a T)> {
+    let mut items: Vec<(EntryIndex, DebugItem)> = addresses
+        .iter()
+        .map(|(item, index)| (*index, *item))
+        .collect();
+    items.sort_by_key(|(index, _)| index.0);
+    items
+        .into_iter()
+        .filter_map(|(index, item)| Some((item, list.get(index.0)?)))
+        .collect()
+}
+
+/// Combine the namespace from the enclosing `DW_TAG_namespace` tags with any namespace
+/// that is encoded in the name itself, returning the full namespace and the bare name.
+fn resolve_namespace<'a>(parents: &[String], name: &'a str) -> (String, &'a str) {
+    // The namespace may be included in name and not through the DW_AT_namespace tag.
+    // Attempt to decode the namespace in the name.
+    let (decoded_namespace, name) = split_namespace_and_name(name);
+
+    if decoded_namespace.is_empty() {
+        (parents.join("::"), name)
+    } else {
+        let mut namespace = parents.to_vec();
+        namespace.extend(decoded_namespace.split("::").map(str::to_string));
+        (namespace.join("::"), name)
+    }
+}
+
+/// Decode a string attribute. Strings may be stored inline, as an offset into `.debug_str`
+/// or `.debug_line_str`, or as an index into `.debug_str_offsets`.
 fn parse_string<ENDIAN: Endianity>(
     attr_value: gimli::AttributeValue<GimliReader<ENDIAN>>,
     unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Option<String> {
-    let gimli::AttributeValue::DebugStrRef(offset) = attr_value else {
-        return None;
-    };
-    let Ok(new_name) = unit_ref.string(offset) else {
-        return None;
+    static UNKNOWN_FORM: std::sync::Once = std::sync::Once::new();
+
+    let new_name = match unit_ref.attr_string(attr_value.clone()) {
+        Ok(new_name) => new_name,
+        Err(gimli::Error::ExpectedStringAttributeValue) => {
+            UNKNOWN_FORM.call_once(|| {
+                log::warn!("Unsupported string attribute form: {:?}", attr_value);
+            });
+            return None;
+        }
+        Err(error) => {
+            log::error!("Unable to read string attribute: {error}");
+            return None;
+        }
     };
     new_name.to_string_lossy().map(|v| v.to_string()).ok()
 }
 
+/// Record that the entry at `offset` lives at `index`. DIE offsets are unique within
+/// well-formed DWARF, so a duplicate is logged and the first entry is kept.
+fn insert_entry(map: &mut HashMap<DebugItem, EntryIndex>, offset: DebugItem, index: usize) {
+    match map.entry(offset) {
+        std::collections::hash_map::Entry::Occupied(_) => {
+            log::warn!("Duplicate entry at {offset:?}, keeping the first one");
+        }
+        std::collections::hash_map::Entry::Vacant(entry) => {
+            entry.insert(EntryIndex(index));
+        }
+    }
+}
+
 fn parse_type<ENDIAN: Endianity>(
     attr: &gimli::Attribute<GimliReader<ENDIAN>>,
     unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
@@ -1160,7 +2202,20 @@ fn parse_type<ENDIAN: Endianity>(
     } else if let gimli::AttributeValue::DebugInfoRef(val) = attr.value() {
         Some(DebugItem::from_debug_info_offset(val))
     } else {
-        panic!("Unknown type index: {:?}", attr.value());
+        log::warn!("Unknown type index: {:?}", attr.value());
+        None
+    }
+}
+
+/// Return the offset of a DIE referred to by `attr`, if that DIE is within this unit.
+fn parse_unit_reference<ENDIAN: Endianity>(
+    attr: &gimli::Attribute<GimliReader<ENDIAN>>,
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> Option<gimli::UnitOffset> {
+    match attr.value() {
+        gimli::AttributeValue::UnitRef(offset) => Some(offset),
+        gimli::AttributeValue::DebugInfoRef(offset) => offset.to_unit_offset(&unit_ref.unit.header),
+        _ => None,
     }
 }
 
@@ -1169,19 +2224,13 @@ fn parse_offset<ENDIAN: Endianity>(
     unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Option<StructOffset> {
     match attr.value() {
-        gimli::AttributeValue::LocationListsRef(_v) => {
-            // panic!(
-            //     "Location lists are unhandled -- but value is located at {:08x}",
-            //     v.0
-            // );
-            None
-        }
+        gimli::AttributeValue::LocationListsRef(_)
+        | gimli::AttributeValue::DebugLocListsIndex(_) => None,
         gimli::AttributeValue::Udata(offset_from_location) => {
             Some(StructOffset(offset_from_location))
         }
         gimli::AttributeValue::Exprloc(expression) => {
-            let result =
-                super::extract::evaluate_expression(expression, unit_ref.unit.encoding()).ok()?;
+            let result = super::extract::evaluate_expression(expression, unit_ref).ok()?;
             use super::extract::{ExpressionResult, VariableLocation};
             let ExpressionResult::Location(VariableLocation::Address(address)) = result else {
                 // print!("Couldn't evaluate expression: ");
@@ -1193,9 +2242,8 @@ fn parse_offset<ENDIAN: Endianity>(
             Some(StructOffset(address))
         }
         _ => {
-            print!("Unsupported value:");
-            super::dump::attribute(attr, unit_ref).ok();
-            panic!();
+            log::warn!("Unsupported member offset: {:?}", attr.value());
+            None
         }
     }
 }
@@ -1207,14 +2255,47 @@ fn parse_location<ENDIAN: Endianity>(
     parse_offset(attr, unit_ref).map(|v| MemoryLocation(v.0))
 }
 
+/// Walk a location list looking for an entry that places the value at a fixed address.
+/// Entries that describe registers or other computed locations are skipped.
+fn parse_location_list<ENDIAN: Endianity>(
+    attr: &gimli::Attribute<GimliReader<ENDIAN>>,
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> Option<MemoryLocation> {
+    use super::extract::{ExpressionResult, VariableLocation};
+    let mut locations = match unit_ref.attr_locations(attr.value()) {
+        Ok(locations) => locations?,
+        Err(e) => {
+            log::error!("Unable to read location list: {}", e);
+            return None;
+        }
+    };
+    while let Ok(Some(entry)) = locations.next() {
+        if let Ok(ExpressionResult::Location(VariableLocation::Address(address))) =
+            super::extract::evaluate_expression(entry.data, unit_ref)
+        {
+            log::trace!("Variable located at {:08x?} via location list", address);
+            return Some(MemoryLocation(address));
+        }
+    }
+    None
+}
+
 fn parse_filename<ENDIAN: Endianity>(
     attr: &gimli::Attribute<GimliReader<ENDIAN>>,
     unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Option<FileName> {
-    let unit = unit_ref.unit;
     let gimli::AttributeValue::FileIndex(file_index) = attr.value() else {
         return None;
     };
+    file_name_from_index(file_index, unit_ref)
+}
+
+/// Resolve an index into the file table of the unit's line program to a full path.
+fn file_name_from_index<ENDIAN: Endianity>(
+    file_index: u64,
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> Option<FileName> {
+    let unit = unit_ref.unit;
     if file_index == 0 && unit.header.version() <= 4 {
         return None;
     }
@@ -1254,17 +2335,108 @@ fn parse_filename<ENDIAN: Endianity>(
     Some(FileName(file_name))
 }
 
-fn parse_variable<ENDIAN: Endianity>(
+/// Collect the address ranges that the unit's code covers, from `DW_AT_low_pc` and
+/// `DW_AT_high_pc` or from `DW_AT_ranges`. Ranges that start at address zero are
+/// dropped, since that is where the linker leaves code that it discarded, and keeping
+/// them would claim addresses that belong to other units.
+fn parse_unit_ranges<ENDIAN: Endianity>(
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> Vec<core::ops::Range<u64>> {
+    let mut ranges = vec![];
+    let mut iter = match unit_ref.unit_ranges() {
+        Ok(iter) => iter,
+        Err(e) => {
+            log::error!("Unable to read unit ranges: {}", e);
+            return ranges;
+        }
+    };
+    while let Ok(Some(range)) = iter.next() {
+        // Code that the linker discarded is left at address zero.
+        if range.begin != 0 && range.begin < range.end {
+            ranges.push(range.begin..range.end);
+        }
+    }
+    ranges
+}
+
+/// Collect the rows of the unit's line program that mark the start of a statement,
+/// along with the names of the files that they refer to.
+fn parse_line_rows<ENDIAN: Endianity>(
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> (HashMap<u64, FileName>, Vec<LineRow>) {
+    let mut files = HashMap::new();
+    let mut lines = vec![];
+    let Some(program) = unit_ref.unit.line_program.clone() else {
+        return (files, lines);
+    };
+    let mut rows = program.rows();
+    while let Ok(Some((_header, row))) = rows.next_row() {
+        if row.end_sequence() || !row.is_stmt() {
+            continue;
+        }
+        let Some(line) = row.line() else {
+            continue;
+        };
+        let file = row.file_index();
+        if let std::collections::hash_map::Entry::Vacant(entry) = files.entry(file) {
+            let Some(file_name) = file_name_from_index(file, unit_ref) else {
+                continue;
+            };
+            entry.insert(file_name);
+        }
+        lines.push(LineRow {
+            file,
+            line: line.get(),
+            address: row.address(),
+        });
+    }
+    (files, lines)
+}
+
+fn parse_const_value<ENDIAN: Endianity>(
+    value: gimli::AttributeValue<GimliReader<ENDIAN>>,
+) -> Option<ConstValue> {
+    Some(match value {
+        gimli::AttributeValue::Data1(value) => ConstValue::Fixed {
+            value: value.into(),
+            size: 1,
+        },
+        gimli::AttributeValue::Data2(value) => ConstValue::Fixed {
+            value: value.into(),
+            size: 2,
+        },
+        gimli::AttributeValue::Data4(value) => ConstValue::Fixed {
+            value: value.into(),
+            size: 4,
+        },
+        gimli::AttributeValue::Data8(value) => ConstValue::Fixed { value, size: 8 },
+        gimli::AttributeValue::Udata(value) => ConstValue::Unsigned(value),
+        gimli::AttributeValue::Sdata(value) => ConstValue::Signed(value),
+        gimli::AttributeValue::Block(block) => ConstValue::Bytes(block.to_slice().ok()?.to_vec()),
+        other => {
+            log::warn!("Unsupported DW_AT_const_value form: {:?}", other);
+            return None;
+        }
+    })
+}
+
+fn parse_variable<'a, ENDIAN: Endianity>(
     attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
-    parents: &[String],
+    mut parents: &'a [String],
+    declaration_namespaces: &'a HashMap<gimli::UnitOffset, Vec<String>>,
     unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+    options: &DebugInfoOptions,
 ) -> Option<Variable> {
     let mut name = None;
     let mut kind = None;
     let mut location = None;
+    let mut tls_offset = None;
+    let mut location_list = None;
+    let mut const_value = None;
     let mut linkage_name = None;
     let mut line = None;
     let mut file = None;
+    let mut origin = None;
 
     for attr in attrs {
         match attr.name() {
@@ -1275,24 +2447,95 @@ fn parse_variable<ENDIAN: Endianity>(
             gimli::constants::DW_AT_linkage_name => {
                 linkage_name = parse_string(attr.value(), unit_ref);
             }
-            gimli::constants::DW_AT_location => {
-                location = parse_location(attr, unit_ref);
+            gimli::constants::DW_AT_abstract_origin | gimli::constants::DW_AT_specification => {
+                origin = parse_unit_reference(attr, unit_ref);
             }
+            gimli::constants::DW_AT_const_value => const_value = parse_const_value(attr.value()),
+            gimli::constants::DW_AT_location => match attr.value() {
+                // Variables described by a location list are kept even if they never live
+                // at a fixed address, so they can still be looked up by name.
+                gimli::AttributeValue::LocationListsRef(_)
+                | gimli::AttributeValue::DebugLocListsIndex(_) => {
+                    location_list = unit_ref.attr_locations_offset(attr.value()).ok().flatten();
+                    location = Some(
+                        options
+                            .load_location_lists
+                            .then(|| parse_location_list(attr, unit_ref))
+                            .flatten(),
+                    );
+                }
+                gimli::AttributeValue::Exprloc(expression) => {
+                    use super::extract::{ExpressionResult, VariableLocation};
+                    match super::extract::evaluate_expression(expression, unit_ref) {
+                        Ok(ExpressionResult::Location(VariableLocation::Address(address))) => {
+                            location = Some(Some(MemoryLocation(address)));
+                        }
+                        Ok(ExpressionResult::Location(VariableLocation::TlsOffset(offset))) => {
+                            log::trace!("Thread-local variable at TLS offset {:08x?}", offset);
+                            tls_offset = Some(offset);
+                            location = Some(None);
+                        }
+                        _ => {}
+                    }
+                }
+                _ => location = parse_location(attr, unit_ref).map(Some),
+            },
             _ => {}
         }
     }
 
+    // Inlined and out-of-line definitions may only refer to the DIE that holds the rest
+    // of their attributes, which may in turn refer to another DIE. Fill in anything that
+    // is missing from each of them, guarding against reference cycles.
+    let mut remaining_origins = 8;
+    while let Some(offset) = origin.take()
+        && remaining_origins > 0
+        && let Ok(entry) = unit_ref.entry(offset)
+    {
+        remaining_origins -= 1;
+        // A definition outside of a namespace may complete a declaration inside one, such
+        // as `int ns::count = 0;` in C++, and takes its namespace from the declaration.
+        if let Some(namespace) = declaration_namespaces.get(&offset) {
+            parents = namespace;
+        }
+        for attr in entry.attrs() {
+            match attr.name() {
+                gimli::constants::DW_AT_name if name.is_none() => {
+                    name = parse_string(attr.value(), unit_ref)
+                }
+                gimli::constants::DW_AT_type if kind.is_none() => kind = parse_type(attr, unit_ref),
+                gimli::constants::DW_AT_decl_file if file.is_none() => {
+                    file = parse_filename(attr, unit_ref)
+                }
+                gimli::constants::DW_AT_decl_line if line.is_none() => line = attr.udata_value(),
+                gimli::constants::DW_AT_linkage_name if linkage_name.is_none() => {
+                    linkage_name = parse_string(attr.value(), unit_ref);
+                }
+                gimli::constants::DW_AT_const_value if const_value.is_none() => {
+                    const_value = parse_const_value(attr.value())
+                }
+                gimli::constants::DW_AT_abstract_origin | gimli::constants::DW_AT_specification => {
+                    origin = parse_unit_reference(attr, unit_ref);
+                }
+                _ => {}
+            }
+        }
+    }
+
     if let Some(mut name) = name {
         let namespace = parents.join("::");
         name = format!("{namespace}::{name}");
         if let Some(kind) = kind
-            && let Some(location) = location
+            && (location.is_some() || const_value.is_some())
         {
             return Some(Variable {
                 name,
                 namespace,
                 kind,
-                location,
+                location: location.flatten(),
+                tls_offset,
+                location_list,
+                const_value,
                 linkage_name,
                 line,
                 file,
@@ -1311,6 +2554,9 @@ fn parse_structure<ENDIAN: Endianity>(
     let mut name = None;
     let mut size = None;
     let mut containing_type = None;
+    let mut declaration = false;
+    let mut file = None;
+    let mut line = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_name => name = parse_string(attr.value(), unit_ref),
@@ -1318,9 +2564,11 @@ fn parse_structure<ENDIAN: Endianity>(
             gimli::constants::DW_AT_alignment => {}
             gimli::constants::DW_AT_accessibility => {}
             gimli::constants::DW_AT_containing_type => containing_type = parse_type(attr, unit_ref),
-            gimli::constants::DW_AT_decl_line => {}
-            gimli::constants::DW_AT_decl_file => {}
-            gimli::constants::DW_AT_declaration => {}
+            gimli::constants::DW_AT_decl_line => line = attr.udata_value(),
+            gimli::constants::DW_AT_decl_file => file = parse_filename(attr, unit_ref),
+            gimli::constants::DW_AT_declaration => {
+                declaration = matches!(attr.value(), gimli::AttributeValue::Flag(true))
+            }
             gimli::constants::DW_AT_calling_convention => {}
             _ => {
                 log::error!(
@@ -1330,22 +2578,11 @@ fn parse_structure<ENDIAN: Endianity>(
             }
         }
     }
+    // Declarations have no size, and are resolved against the full definition later.
     if let Some(name) = name
-        && let Some(size) = size
+        && (size.is_some() || declaration)
     {
-        // The namespace may be included in name and not through the DW_AT_namespace tag.
-        // Attempt to decode the namespace in the name.
-        let (decoded_namespace, name) = split_namespace_and_name(&name);
-
-        let namespace = if decoded_namespace.is_empty() {
-            namespace.join("::")
-        } else {
-            let decoded_namespace: Vec<String> =
-                decoded_namespace.split("::").map(str::to_string).collect();
-            let mut namespace = namespace.to_vec();
-            namespace.extend(decoded_namespace);
-            namespace.join("::")
-        };
+        let (namespace, name) = resolve_namespace(namespace, &name);
 
         return Some(Structure {
             members: vec![],
@@ -1355,6 +2592,9 @@ fn parse_structure<ENDIAN: Endianity>(
             namespace,
             size,
             containing_type,
+            declaration,
+            file,
+            line,
         });
     }
     None
@@ -1367,14 +2607,16 @@ fn parse_union<ENDIAN: Endianity>(
 ) -> Option<Union> {
     let mut name = None;
     let mut size = None;
+    let mut file = None;
+    let mut line = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_name => name = parse_string(attr.value(), unit_ref),
             gimli::constants::DW_AT_byte_size => size = attr.udata_value(),
             gimli::constants::DW_AT_alignment => {}
             gimli::constants::DW_AT_accessibility => {}
-            gimli::constants::DW_AT_decl_line => {}
-            gimli::constants::DW_AT_decl_file => {}
+            gimli::constants::DW_AT_decl_line => line = attr.udata_value(),
+            gimli::constants::DW_AT_decl_file => file = parse_filename(attr, unit_ref),
             gimli::constants::DW_AT_declaration => {}
             gimli::constants::DW_AT_calling_convention => {}
             // gimli::constants::DW_AT_containing_type => containing_type = parse_type(attr, unit_ref),
@@ -1389,25 +2631,16 @@ fn parse_union<ENDIAN: Endianity>(
     if let Some(name) = name
         && let Some(size) = size
     {
-        // The namespace may be included in name and not through the DW_AT_namespace tag.
-        // Attempt to decode the namespace in the name.
-        let (decoded_namespace, name) = split_namespace_and_name(&name);
-
-        let namespace = if decoded_namespace.is_empty() {
-            namespace.join("::")
-        } else {
-            let decoded_namespace: Vec<String> =
-                decoded_namespace.split("::").map(str::to_string).collect();
-            let mut namespace = namespace.to_vec();
-            namespace.extend(decoded_namespace);
-            namespace.join("::")
-        };
+        let (namespace, name) = resolve_namespace(namespace, &name);
 
         return Some(Union {
             members: vec![],
+            generics: vec![],
             name: name.into(),
             namespace,
             size,
+            file,
+            line,
         });
     }
     None
@@ -1452,10 +2685,12 @@ fn parse_generic_parameter<ENDIAN: Endianity>(
 ) -> Option<GenericParameter> {
     let mut name = None;
     let mut kind = None;
+    let mut value = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_name => name = parse_string(attr.value(), unit_ref),
             gimli::constants::DW_AT_type => kind = parse_type(attr, unit_ref),
+            gimli::constants::DW_AT_const_value => value = parse_const_value(attr.value()),
             gimli::constants::DW_AT_data_member_location => {}
             gimli::constants::DW_AT_alignment => {}
             gimli::constants::DW_AT_accessibility => {}
@@ -1473,7 +2708,7 @@ fn parse_generic_parameter<ENDIAN: Endianity>(
         }
     }
     if let Some(kind) = kind {
-        return Some(GenericParameter { name, kind });
+        return Some(GenericParameter { name, kind, value });
     }
     None
 }
@@ -1488,7 +2723,7 @@ fn parse_enumerator<ENDIAN: Endianity>(
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_const_value => {
-                discriminant = Some(attr.udata_value());
+                discriminant = Some(parse_discriminant(attr.value()));
             }
             gimli::constants::DW_AT_name => name = parse_string(attr.value(), unit_ref),
             _ => {
@@ -1514,14 +2749,49 @@ fn parse_enumerator<ENDIAN: Endianity>(
     }
 }
 
+/// Read a discriminant value. Fixed-size forms are zero-extended here, and are
+/// sign-extended later once the type of the discriminant is known.
+fn parse_discriminant<ENDIAN: Endianity>(
+    value: gimli::AttributeValue<GimliReader<ENDIAN>>,
+) -> Option<i128> {
+    match value {
+        gimli::AttributeValue::Sdata(value) => Some(value.into()),
+        gimli::AttributeValue::Data16(value) => Some(value as i128),
+        // 128-bit values may also be stored as a block of bytes in the target byte order.
+        gimli::AttributeValue::Block(block) if block.len() <= 16 => {
+            let big_endian = block.endian().is_big_endian();
+            let mut bytes = block.to_slice().ok()?.to_vec();
+            if big_endian {
+                bytes.reverse();
+            }
+            bytes.resize(16, 0);
+            Some(u128::from_le_bytes(bytes.try_into().ok()?) as i128)
+        }
+        value => value.udata_value().map(i128::from),
+    }
+}
+
+/// Sign- or zero-extend a discriminant that is `size` bytes wide.
+pub(crate) fn normalize_discriminant(value: i128, size: u64, signed: bool) -> i128 {
+    if size == 0 || size >= 16 {
+        return value;
+    }
+    let shift = 128 - size as u32 * 8;
+    if signed {
+        (value << shift) >> shift
+    } else {
+        ((value as u128) << shift >> shift) as i128
+    }
+}
+
 fn parse_enum_variant<ENDIAN: Endianity>(
     attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
-) -> Option<u64> {
+) -> Option<i128> {
     let mut discriminant = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_discr_value => {
-                discriminant = attr.udata_value();
+                discriminant = parse_discriminant(attr.value());
             }
             _ => {
                 log::error!(
@@ -1578,6 +2848,8 @@ fn parse_enumeration_type<ENDIAN: Endianity>(
     let mut name = None;
     let mut size = None;
     let mut offset = None;
+    let mut file = None;
+    let mut line = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_type => kind = parse_type(attr, unit_ref),
@@ -1586,8 +2858,8 @@ fn parse_enumeration_type<ENDIAN: Endianity>(
             gimli::constants::DW_AT_alignment => {}
             gimli::constants::DW_AT_data_member_location => offset = parse_offset(attr, unit_ref),
             gimli::constants::DW_AT_accessibility => {}
-            gimli::constants::DW_AT_decl_line => {}
-            gimli::constants::DW_AT_decl_file => {}
+            gimli::constants::DW_AT_decl_line => line = attr.udata_value(),
+            gimli::constants::DW_AT_decl_file => file = parse_filename(attr, unit_ref),
             gimli::constants::DW_AT_declaration => {}
             gimli::constants::DW_AT_calling_convention => {}
             gimli::constants::DW_AT_enum_class => {}
@@ -1603,19 +2875,7 @@ fn parse_enumeration_type<ENDIAN: Endianity>(
         && let Some(size) = size
         && let Some(discriminant_kind) = kind
     {
-        // The namespace may be included in name and not through the DW_AT_namespace tag.
-        // Attempt to decode the namespace in the name.
-        let (decoded_namespace, name) = split_namespace_and_name(&name);
-
-        let namespace = if decoded_namespace.is_empty() {
-            namespace.join("::")
-        } else {
-            let decoded_namespace: Vec<String> =
-                decoded_namespace.split("::").map(str::to_string).collect();
-            let mut namespace = namespace.to_vec();
-            namespace.extend(decoded_namespace);
-            namespace.join("::")
-        };
+        let (namespace, name) = resolve_namespace(namespace, &name);
 
         return Some(Enumeration {
             name: name.into(),
@@ -1624,6 +2884,9 @@ fn parse_enumeration_type<ENDIAN: Endianity>(
             discriminant_offset: offset.unwrap_or(StructOffset(0)),
             discriminant_kind,
             variants: vec![],
+            generics: vec![],
+            file,
+            line,
         });
     }
     None
@@ -1659,12 +2922,15 @@ fn parse_enum_discriminant<ENDIAN: Endianity>(
 
 fn parse_array<ENDIAN: Endianity>(
     attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
+    namespace: &[String],
     unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
 ) -> Option<PartialArray> {
     let mut kind = None;
+    let mut byte_stride = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_type => kind = parse_type(attr, unit_ref),
+            gimli::constants::DW_AT_byte_stride => byte_stride = attr.udata_value(),
             gimli::constants::DW_AT_GNU_vector => {}
             _ => {
                 log::error!(
@@ -1675,7 +2941,13 @@ fn parse_array<ENDIAN: Endianity>(
         }
     }
     if let Some(kind) = kind {
-        return Some(PartialArray { kind });
+        return Some(PartialArray {
+            kind,
+            namespace: namespace.join("::"),
+            byte_stride,
+            subranges: vec![],
+            incomplete: false,
+        });
     }
     None
 }
@@ -1684,13 +2956,19 @@ fn parse_subrange<ENDIAN: Endianity>(
     attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
 ) -> Option<Subrange> {
     let mut lower_bound = None;
+    let mut upper_bound = None;
     let mut count = None;
+    let mut byte_stride = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_type => {}
+            gimli::constants::DW_AT_byte_stride => byte_stride = attr.udata_value(),
             gimli::constants::DW_AT_lower_bound => lower_bound = attr.udata_value(),
+            gimli::constants::DW_AT_upper_bound => upper_bound = attr.udata_value(),
             gimli::constants::DW_AT_count => {
-                count = attr.udata_value().map(|udata| udata as usize);
+                count = attr
+                    .udata_value()
+                    .and_then(|udata| usize::try_from(udata).ok());
             }
             _ => {
                 log::error!(
@@ -1700,12 +2978,26 @@ fn parse_subrange<ENDIAN: Endianity>(
             }
         }
     }
-    if let Some(lower_bound) = lower_bound
-        && let Some(count) = count
+    // C compilers omit the lower bound, since it is always 0, and describe the
+    // size using the upper bound rather than a count. Older versions of GCC describe
+    // zero-length arrays with an upper bound of -1, one below the lower bound.
+    let lower_bound = lower_bound.unwrap_or(0);
+    if count.is_none()
+        && let Some(upper_bound) = upper_bound
     {
-        return Some(Subrange { lower_bound, count });
+        count = Some(if upper_bound.wrapping_add(1) == lower_bound {
+            0
+        } else {
+            usize::try_from(upper_bound.checked_sub(lower_bound)?)
+                .ok()?
+                .checked_add(1)?
+        });
     }
-    None
+    Some(Subrange {
+        lower_bound,
+        count: count?,
+        byte_stride,
+    })
 }
 
 fn parse_pointer<ENDIAN: Endianity>(
@@ -1729,13 +3021,64 @@ fn parse_pointer<ENDIAN: Endianity>(
             }
         }
     }
-    kind.map(|kind| Pointer {
+    // A pointer without a type is a `void *`.
+    Some(Pointer {
         name,
         namespace: namespace.join("::"),
         kind,
     })
 }
 
+fn parse_type_alias<ENDIAN: Endianity>(
+    attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> TypeAlias {
+    let mut name = None;
+    let mut kind = None;
+    for attr in attrs {
+        match attr.name() {
+            gimli::constants::DW_AT_type => kind = parse_type(attr, unit_ref),
+            gimli::constants::DW_AT_name => name = parse_string(attr.value(), unit_ref),
+            gimli::constants::DW_AT_decl_file => {}
+            gimli::constants::DW_AT_decl_line => {}
+            gimli::constants::DW_AT_decl_column => {}
+            gimli::constants::DW_AT_alignment => {}
+            _ => {
+                log::error!(
+                    "Unexpected typedef attr: {:?}",
+                    attr.name().static_string().unwrap_or("<unknown>")
+                );
+            }
+        }
+    }
+    TypeAlias { name, kind }
+}
+
+fn parse_subroutine_type<ENDIAN: Endianity>(
+    attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
+    unit_ref: gimli::UnitRef<GimliReader<ENDIAN>>,
+) -> SubroutineType {
+    let mut return_type = None;
+    for attr in attrs {
+        match attr.name() {
+            gimli::constants::DW_AT_type => return_type = parse_type(attr, unit_ref),
+            gimli::constants::DW_AT_name => {}
+            gimli::constants::DW_AT_prototyped => {}
+            _ => {
+                log::error!(
+                    "Unexpected subroutine type attr: {:?}",
+                    attr.name().static_string().unwrap_or("<unknown>")
+                );
+            }
+        }
+    }
+    // Parameters are added as the `DW_TAG_formal_parameter` children are parsed.
+    SubroutineType {
+        return_type,
+        parameter_types: vec![],
+    }
+}
+
 fn parse_base_type<ENDIAN: Endianity>(
     attrs: &[gimli::Attribute<GimliReader<ENDIAN>>],
     namespace: &[String],
@@ -1743,11 +3086,16 @@ fn parse_base_type<ENDIAN: Endianity>(
 ) -> Option<BaseType> {
     let mut name = None;
     let mut size = None;
+    let mut encoding = None;
     for attr in attrs {
         match attr.name() {
             gimli::constants::DW_AT_name => name = parse_string(attr.value(), unit_ref),
             gimli::constants::DW_AT_byte_size => size = attr.udata_value(),
-            gimli::constants::DW_AT_encoding => {}
+            gimli::constants::DW_AT_encoding => {
+                if let gimli::AttributeValue::Encoding(value) = attr.value() {
+                    encoding = Some(value);
+                }
+            }
             _ => {
                 log::error!(
                     "Unexpected base_type attr: {:?}",
@@ -1763,6 +3111,7 @@ fn parse_base_type<ENDIAN: Endianity>(
             name,
             namespace: namespace.join("::"),
             size,
+            encoding: encoding.unwrap_or(gimli::constants::DW_ATE_unsigned),
         });
     }
     None
@@ -0,0 +1,357 @@
+//! Read entire values out of the target at once, producing a tree that mirrors the type
+//! of the value. This is what pretty-printers and snapshot tools are built on.
+
+use std::collections::HashSet;
+
+use crate::{
+    debug_types::{BaseValue, DebugArrayItem, DebugEnumeration, DebugPointer, DebugTypeError},
+    memory::Read,
+    unit_info::DebugItem,
+};
+
+/// Limits on how much of a value [`DebugVariable::read_value()`] reads. Values on a live
+/// target can be large or self-referential, so the defaults are conservative.
+///
+/// [`DebugVariable::read_value()`]: crate::debug_types::DebugVariable::read_value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueOptions {
+    /// How many levels of structures, enumerations, arrays and pointers to descend into.
+    /// Anything deeper is returned as [`Value::Truncated`].
+    pub max_depth: usize,
+    /// The most elements to read from a single array. Longer arrays are cut short.
+    pub max_array_elements: usize,
+    /// The longest `&str` or `String` to read as text. Longer strings, which usually
+    /// mean that the length is garbage, are read as a plain structure instead.
+    pub max_string_length: u64,
+    /// Read the values that pointers point to, rather than just the addresses they hold.
+    pub follow_pointers: bool,
+}
+
+impl Default for ValueOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 8,
+            max_array_elements: 64,
+            max_string_length: 1024,
+            follow_pointers: false,
+        }
+    }
+}
+
+/// A value read from the target, as returned by [`DebugVariable::read_value()`].
+///
+/// Values can be printed the way a debugger would print them. The alternate form,
+/// `{:#}`, spreads structures and arrays over several indented lines.
+///
+/// [`DebugVariable::read_value()`]: crate::debug_types::DebugVariable::read_value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A structure, with each member in declaration order. Anonymous members are named
+    /// after their position, such as `anonymous[2]`.
+    Struct {
+        name: String,
+        fields: Vec<(String, Value)>,
+    },
+    /// The active variant of an enumeration. C-style enumerations and Rust variants
+    /// without fields have no payload.
+    Enum {
+        name: String,
+        variant: String,
+        payload: Option<Box<Value>>,
+    },
+    /// The elements of an array. Multi-dimensional C arrays are flattened in row-major
+    /// order. At most [`ValueOptions::max_array_elements`] elements are read, and an
+    /// array that was cut short ends with [`Value::Truncated`].
+    Array(Vec<Value>),
+    /// A pointer and, if it was followed, the value that it points to. Pointers aren't
+    /// followed if they are null, point to something without a readable type such as a
+    /// function, or point back to a value that is already being read.
+    Pointer {
+        address: u64,
+        pointee: Option<Box<Value>>,
+    },
+    Base(BaseValue),
+    /// The text of a `&str` or `String`.
+    String(String),
+    /// The raw contents of a union, since there's no way to know which member is active.
+    Union {
+        bytes: Vec<u8>,
+    },
+    /// A value nested deeper than [`ValueOptions::max_depth`] allows.
+    Truncated,
+    /// A value whose memory couldn't be read, or that has no address. The rest of the
+    /// value is still read.
+    Unreadable,
+    /// A value that couldn't be read for any other reason, such as a type that isn't
+    /// understood. This holds the message of the error.
+    Invalid(String),
+}
+
+/// Turn a failed memory read into [`Value::Unreadable`], so that one bad address doesn't
+/// stop the rest of a value from being read.
+fn unreadable_on_read_error(value: Result<Value, DebugTypeError>) -> Result<Value, DebugTypeError> {
+    match value {
+        Err(DebugTypeError::ReadError | DebugTypeError::LocationMissing) => Ok(Value::Unreadable),
+        value => value,
+    }
+}
+
+struct ValueReader<'s, S: Read + ?Sized> {
+    memory_source: &'s mut S,
+    options: ValueOptions,
+    /// The address and type of the value being read and of each pointee that is
+    /// currently being read, so that a pointer leading back to one of them isn't
+    /// followed again.
+    visiting: HashSet<(u64, DebugItem)>,
+}
+
+/// Read the value of `item` along with everything inside it.
+pub(crate) fn read_value<S: Read + ?Sized>(
+    item: &DebugArrayItem<'_>,
+    memory_source: &mut S,
+    options: ValueOptions,
+) -> Result<Value, DebugTypeError> {
+    let mut visiting = HashSet::new();
+    if let Some(location) = item.location() {
+        visiting.insert((location.0, item.kind()));
+    }
+    ValueReader {
+        memory_source,
+        options,
+        visiting,
+    }
+    .read_item(item, 0)
+}
+
+impl<S: Read + ?Sized> ValueReader<'_, S> {
+    /// Read a value inside the one being read. Errors become leaves of the tree, so that
+    /// one bad member doesn't stop the rest from being read.
+    fn read(&mut self, item: &DebugArrayItem<'_>, depth: usize) -> Value {
+        match unreadable_on_read_error(self.read_item(item, depth)) {
+            Ok(value) => value,
+            Err(error) => Value::Invalid(error.to_string()),
+        }
+    }
+
+    fn read_item(
+        &mut self,
+        item: &DebugArrayItem<'_>,
+        depth: usize,
+    ) -> Result<Value, DebugTypeError> {
+        if let Ok(base_type) = item.base_type() {
+            return unreadable_on_read_error(base_type.value(self.memory_source).map(Value::Base));
+        }
+        if let Ok(pointer) = item.pointer() {
+            return unreadable_on_read_error(self.read_pointer(pointer, depth));
+        }
+        if let Ok(union) = item.union() {
+            let bytes = union.read_bytes(self.memory_source);
+            return unreadable_on_read_error(bytes.map(|bytes| Value::Union { bytes }));
+        }
+        if depth >= self.options.max_depth {
+            return Ok(Value::Truncated);
+        }
+        if let Ok(enumeration) = item.enumeration() {
+            return unreadable_on_read_error(self.read_enumeration(enumeration, depth));
+        }
+        if let Ok(array) = item.array() {
+            let mut elements = vec![];
+            for element in array.iter()?.take(self.options.max_array_elements) {
+                elements.push(self.read(&element, depth + 1));
+            }
+            if array.count() > self.options.max_array_elements {
+                elements.push(Value::Truncated);
+            }
+            return Ok(Value::Array(elements));
+        }
+        let structure = item.structure()?;
+        let name = structure.name();
+        if (name == "&str" || name == "String" || name.starts_with("String<"))
+            && let Ok(text) =
+                structure.as_rust_string(self.memory_source, self.options.max_string_length)
+        {
+            return Ok(Value::String(text));
+        }
+        let mut fields = vec![];
+        for (index, member) in structure.members_bound().enumerate() {
+            let name = member
+                .name()
+                .map(|name| name.to_owned())
+                .unwrap_or_else(|| format!("anonymous[{index}]"));
+            fields.push((name, self.read(&member.as_item(), depth + 1)));
+        }
+        Ok(Value::Struct {
+            name: structure.name().to_owned(),
+            fields,
+        })
+    }
+
+    fn read_enumeration(
+        &mut self,
+        enumeration: DebugEnumeration<'_>,
+        depth: usize,
+    ) -> Result<Value, DebugTypeError> {
+        let variant = enumeration.variant(self.memory_source)?;
+        let has_payload = variant.kind() != enumeration.discriminant_kind()
+            && variant
+                .structure()
+                .is_ok_and(|payload| !payload.structure().members().is_empty());
+        let payload = if has_payload {
+            Some(Box::new(self.read(&variant.as_item(), depth + 1)))
+        } else {
+            None
+        };
+        Ok(Value::Enum {
+            name: enumeration.name().to_owned(),
+            variant: variant.name().to_owned(),
+            payload,
+        })
+    }
+
+    fn read_pointer(
+        &mut self,
+        pointer: DebugPointer<'_>,
+        depth: usize,
+    ) -> Result<Value, DebugTypeError> {
+        let address = pointer.target_address(self.memory_source)?;
+        if !self.options.follow_pointers || address == 0 || depth >= self.options.max_depth {
+            return Ok(Value::Pointer {
+                address,
+                pointee: None,
+            });
+        }
+        let pointee = pointer
+            .follow(self.memory_source)?
+            .pointee_item()
+            .filter(|pointee| self.visiting.insert((address, pointee.kind())))
+            .map(|pointee| {
+                let value = self.read(&pointee, depth + 1);
+                self.visiting.remove(&(address, pointee.kind()));
+                Box::new(value)
+            });
+        Ok(Value::Pointer { address, pointee })
+    }
+}
+
+/// Whether these are the fields of a tuple or tuple variant, which rustc names `__0`,
+/// `__1` and so on.
+fn is_tuple(fields: &[(String, Value)]) -> bool {
+    !fields.is_empty() && fields.iter().all(|(name, _)| name.starts_with("__"))
+}
+
+/// Write `items` between `open` and `close`, either on one line or, when `indent` is
+/// given, one item per line at that depth.
+fn write_list<T>(
+    f: &mut core::fmt::Formatter<'_>,
+    open: &str,
+    close: &str,
+    items: &[T],
+    indent: Option<usize>,
+    mut write_item: impl FnMut(&mut core::fmt::Formatter<'_>, &T) -> core::fmt::Result,
+) -> core::fmt::Result {
+    write!(f, "{open}")?;
+    for (index, item) in items.iter().enumerate() {
+        match indent {
+            Some(depth) => write!(f, "\n{:width$}", "", width = 4 * (depth + 1))?,
+            None if index > 0 => write!(f, ", ")?,
+            None => {}
+        }
+        write_item(f, item)?;
+        if indent.is_some() {
+            write!(f, ",")?;
+        }
+    }
+    match indent {
+        Some(depth) => write!(
+            f,
+            "\n{:width$}{}",
+            "",
+            close.trim_start(),
+            width = 4 * depth
+        ),
+        None => write!(f, "{close}"),
+    }
+}
+
+impl Value {
+    fn write(&self, f: &mut core::fmt::Formatter<'_>, indent: Option<usize>) -> core::fmt::Result {
+        let inner = indent.map(|depth| depth + 1);
+        match self {
+            Value::Struct { name, fields } => {
+                write!(f, "{name}")?;
+                Value::write_fields(f, fields, indent)
+            }
+            Value::Enum {
+                name,
+                variant,
+                payload,
+            } => {
+                write!(f, "{name}::{variant}")?;
+                match payload.as_deref() {
+                    Some(Value::Struct { fields, .. }) => Value::write_fields(f, fields, indent),
+                    Some(payload) => {
+                        write!(f, "(")?;
+                        payload.write(f, indent)?;
+                        write!(f, ")")
+                    }
+                    None => Ok(()),
+                }
+            }
+            Value::Array(elements) => write_list(f, "[", "]", elements, indent, |f, element| {
+                element.write(f, inner)
+            }),
+            Value::Pointer { address, pointee } => {
+                write!(f, "{address:#x}")?;
+                if let Some(pointee) = pointee {
+                    write!(f, " -> ")?;
+                    pointee.write(f, indent)?;
+                }
+                Ok(())
+            }
+            Value::Base(value) => write!(f, "{value}"),
+            Value::String(text) => write!(f, "{text:?}"),
+            Value::Union { bytes } => {
+                write!(f, "union")?;
+                for byte in bytes {
+                    write!(f, " {byte:02x}")?;
+                }
+                Ok(())
+            }
+            Value::Truncated => write!(f, "..."),
+            Value::Unreadable => write!(f, "<unreadable>"),
+            Value::Invalid(message) => write!(f, "<invalid: {message}>"),
+        }
+    }
+
+    /// Write the fields of a structure or enum variant that follow its name.
+    fn write_fields(
+        f: &mut core::fmt::Formatter<'_>,
+        fields: &[(String, Value)],
+        indent: Option<usize>,
+    ) -> core::fmt::Result {
+        let inner = indent.map(|depth| depth + 1);
+        if fields.is_empty() {
+            Ok(())
+        } else if is_tuple(fields) {
+            write_list(f, "(", ")", fields, indent, |f, (_, value)| {
+                value.write(f, inner)
+            })
+        } else {
+            let (open, close) = if indent.is_some() {
+                (" {", "}")
+            } else {
+                (" { ", " }")
+            };
+            write_list(f, open, close, fields, indent, |f, (name, value)| {
+                write!(f, "{name}: ")?;
+                value.write(f, inner)
+            })
+        }
+    }
+}
+
+impl core::fmt::Display for Value {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write(f, f.alternate().then_some(0))
+    }
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::load;
+
+#[test]
+fn address_inside_variable() {
+    let info = load("basic");
+    let (variable, offset) = info
+        .variable_at_address(common::symbol_address("basic", "SENSOR") + 8)
+        .unwrap();
+    assert_eq!(variable.name(), "::SENSOR");
+    assert_eq!(offset, 8);
+    assert_eq!(
+        info.describe_address(common::symbol_address("basic", "SENSOR") + 10)
+            .as_deref(),
+        Some("SENSOR.position.y")
+    );
+}
+
+#[test]
+fn address_outside_every_variable() {
+    let info = load("basic");
+    assert!(info.variable_at_address(0).is_none());
+}
+
+#[test]
+fn overlapping_variables() {
+    // `INNER` occupies 0x500010..0x500014, inside `OUTER` at 0x500000..0x500040.
+    let info = load("overlay");
+    let (variable, offset) = info.variable_at_address(0x500012).unwrap();
+    assert_eq!(variable.name(), "::INNER");
+    assert_eq!(offset, 2);
+
+    // `INNER` starts closer to these addresses, but doesn't contain them.
+    let (variable, offset) = info.variable_at_address(0x500020).unwrap();
+    assert_eq!(variable.name(), "::OUTER");
+    assert_eq!(offset, 0x20);
+    let (variable, offset) = info.variable_at_address(0x50003f).unwrap();
+    assert_eq!(variable.name(), "::OUTER");
+    assert_eq!(offset, 0x3f);
+
+    assert!(info.variable_at_address(0x500040).is_none());
+}
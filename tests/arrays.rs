@@ -0,0 +1,108 @@
+mod common;
+
+use common::{FakeReader, load, variable};
+use tasru::debug_types::DebugTypeError;
+
+#[test]
+fn byte_arrays_are_read_at_once() {
+    let info = load("basic");
+    let mut memory = FakeReader::from_fixture("basic");
+    let bytes = variable(&info, "BYTES").array().unwrap();
+    assert_eq!(bytes.read_bytes(&mut memory).unwrap(), [1, 2, 3, 0xff]);
+}
+
+#[test]
+fn only_byte_arrays_are_read_as_bytes() {
+    let info = load("basic");
+    let mut memory = FakeReader::from_fixture("basic");
+    let words = variable(&info, "WORDS").array().unwrap();
+    assert!(matches!(
+        words.read_bytes(&mut memory),
+        Err(DebugTypeError::SizeError(4))
+    ));
+    assert_eq!(
+        words.read_u32_array(&mut memory).unwrap(),
+        [0x1234_5678, 0x9abc_def0]
+    );
+    let bytes = variable(&info, "BYTES").array().unwrap();
+    assert!(matches!(
+        bytes.read_u32_array(&mut memory),
+        Err(DebugTypeError::SizeError(1))
+    ));
+}
+
+/// Build the Dwarf sections for a single unit holding a variable `NAME` at 0x1000,
+/// whose type is an array of `int` with one dimension for each upper bound.
+fn array_with_upper_bounds(upper_bounds: &[u64]) -> tasru::DebugInfo {
+    use gimli::write::{Address, AttributeValue, DwarfUnit, EndianVec, Expression, Sections};
+
+    let encoding = gimli::Encoding {
+        format: gimli::Format::Dwarf32,
+        version: 4,
+        address_size: 8,
+    };
+    let mut dwarf = DwarfUnit::new(encoding);
+    let root = dwarf.unit.root();
+    dwarf.unit.get_mut(root).set(
+        gimli::DW_AT_name,
+        AttributeValue::String(b"arrays.c".to_vec()),
+    );
+
+    let int = dwarf.unit.add(root, gimli::DW_TAG_base_type);
+    let entry = dwarf.unit.get_mut(int);
+    entry.set(gimli::DW_AT_name, AttributeValue::String(b"int".to_vec()));
+    entry.set(gimli::DW_AT_byte_size, AttributeValue::Data1(4));
+    entry.set(
+        gimli::DW_AT_encoding,
+        AttributeValue::Encoding(gimli::DW_ATE_signed),
+    );
+
+    let array = dwarf.unit.add(root, gimli::DW_TAG_array_type);
+    dwarf
+        .unit
+        .get_mut(array)
+        .set(gimli::DW_AT_type, AttributeValue::UnitRef(int));
+    for upper_bound in upper_bounds {
+        let subrange = dwarf.unit.add(array, gimli::DW_TAG_subrange_type);
+        dwarf.unit.get_mut(subrange).set(
+            gimli::DW_AT_upper_bound,
+            AttributeValue::Data8(*upper_bound),
+        );
+    }
+
+    let variable = dwarf.unit.add(root, gimli::DW_TAG_variable);
+    let entry = dwarf.unit.get_mut(variable);
+    entry.set(gimli::DW_AT_name, AttributeValue::String(b"NAME".to_vec()));
+    entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(array));
+    let mut location = Expression::new();
+    location.op_addr(Address::Constant(0x1000));
+    entry.set(gimli::DW_AT_location, AttributeValue::Exprloc(location));
+
+    let mut sections = Sections::new(EndianVec::new(gimli::LittleEndian));
+    dwarf.write(&mut sections).unwrap();
+    let mut map = std::collections::HashMap::new();
+    sections
+        .for_each(|id, data| {
+            map.insert(id.name(), data.slice().to_vec());
+            Ok::<_, ()>(())
+        })
+        .unwrap();
+    tasru::DebugInfo::from_section_map(map, true).unwrap()
+}
+
+#[test]
+fn upper_bound_of_minus_one_is_an_empty_array() {
+    let info = array_with_upper_bounds(&[u64::MAX]);
+    let array = variable(&info, "NAME").array().unwrap();
+    assert_eq!(array.count(), 0);
+    assert_eq!(array.dimensions(), [(0, 0)]);
+
+    let info = array_with_upper_bounds(&[1, 2]);
+    assert_eq!(variable(&info, "NAME").array().unwrap().count(), 6);
+}
+
+#[test]
+fn arrays_too_large_to_count_are_dropped() {
+    let info = array_with_upper_bounds(&[u64::from(u32::MAX), u64::from(u32::MAX)]);
+    assert!(variable(&info, "NAME").array().is_err());
+}
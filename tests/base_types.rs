@@ -0,0 +1,72 @@
+mod common;
+
+use common::{FakeReader, load, variable};
+
+#[test]
+fn signed_values_are_sign_extended() {
+    let info = load("basic");
+    let mut memory = FakeReader::from_fixture("basic");
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let reading = sensor.member_named("reading").unwrap().base_type().unwrap();
+    assert_eq!(reading.as_i32(&mut memory), Some(-40));
+    assert_eq!(reading.as_i64(&mut memory), Some(-40));
+    assert_eq!(sensor.read_field_i32("reading", &mut memory).unwrap(), -40);
+
+    let origin = variable(&info, "ORIGIN").structure().unwrap();
+    let x = origin.member_named("x").unwrap().base_type().unwrap();
+    assert_eq!(x.as_i16(&mut memory), Some(-3));
+    assert_eq!(x.as_i64(&mut memory), Some(-3));
+}
+
+#[test]
+fn negative_value_round_trips_through_as_i64() {
+    let info = load("basic");
+    let origin = variable(&info, "ORIGIN").structure().unwrap();
+    let y = origin.member_named("y").unwrap();
+    let mut memory = FakeReader::new();
+    memory.write(y.location().unwrap(), &(-1234i16).to_le_bytes());
+    let y = y.base_type().unwrap();
+    assert_eq!(y.as_i64(&mut memory), Some(-1234));
+    assert_eq!(y.as_i128(&mut memory), Some(-1234));
+    assert_eq!(y.as_u16(&mut memory), Some(-1234i16 as u16));
+}
+
+#[test]
+fn unsigned_values_are_zero_extended() {
+    let info = load("basic");
+    let mut memory = FakeReader::from_fixture("basic");
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let flags = sensor.member_named("flags").unwrap().base_type().unwrap();
+    assert_eq!(flags.as_u8(&mut memory), Some(0xff));
+    assert_eq!(flags.as_i64(&mut memory), Some(255));
+    assert_eq!(flags.as_i32(&mut memory), Some(255));
+    assert_eq!(flags.as_i128(&mut memory), Some(255));
+    assert_eq!(flags.as_i8(&mut memory), None);
+    assert_eq!(sensor.read_field_i32("flags", &mut memory).unwrap(), 255);
+}
+
+#[test]
+fn only_checked_bool_reads_look_at_the_encoding() {
+    let info = load("basic");
+    let mut memory = FakeReader::from_fixture("basic");
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let flags = sensor.member_named("flags").unwrap().base_type().unwrap();
+    assert_eq!(flags.as_bool(&mut memory), Some(true));
+    assert_eq!(flags.as_bool_strict(&mut memory), None);
+    assert_eq!(flags.as_bool_checked(&mut memory), None);
+}
+
+#[test]
+fn bound_members_have_locations_and_raw_members_do_not_shadow() {
+    let info = load("basic");
+    let sensor = variable(&info, "SENSOR");
+    let base = sensor.location().unwrap();
+    let sensor = sensor.structure().unwrap();
+    let raw: &[tasru::unit_info::StructureMember] = sensor.members();
+    assert_eq!(raw.len(), 4);
+    let bound: Vec<_> = sensor.members_bound().collect();
+    assert_eq!(bound.len(), raw.len());
+    for (bound, raw) in bound.iter().zip(raw) {
+        assert_eq!(bound.location().unwrap(), (base + raw.offset()).0);
+    }
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use std::{path::PathBuf, process::Command};
+
+use common::load;
+
+/// A directory to build generated code in, unique to this test process.
+fn scratch_directory(name: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("tasru-codegen-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn c_declaration_points_to_void_and_opaque_enumerations() {
+    let info = load("codegen");
+    let header = info.to_c_declaration("codegen::Header").unwrap();
+    assert!(header.contains("    void *context;\n"), "{header}");
+    assert!(header.contains("struct codegen_Shape;\n"), "{header}");
+    assert!(
+        header.contains("    struct codegen_Shape *shape;\n"),
+        "{header}"
+    );
+    assert!(
+        header.contains("    /* marker is zero-sized */\n"),
+        "{header}"
+    );
+
+    // The generated header checks its own size with `_Static_assert`.
+    let directory = scratch_directory("c");
+    let source = directory.join("header.c");
+    std::fs::write(&source, &header).unwrap();
+    let output = Command::new("cc")
+        .arg("-fsyntax-only")
+        .arg(&source)
+        .output()
+        .expect("couldn't run the C compiler");
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn rust_definition_compiles_to_the_right_size() {
+    let info = load("codegen");
+    let definition = info.to_rust_definition("codegen::Header").unwrap();
+    let size = info
+        .structure_from_type("codegen::Header")
+        .unwrap()
+        .size()
+        .unwrap();
+
+    let directory = scratch_directory("rust");
+    let source = directory.join("header.rs");
+    std::fs::write(
+        &source,
+        format!(
+            "#![allow(dead_code)]\n{definition}\n\
+             fn main() {{\n    \
+                 print!(\"{{}}\", core::mem::size_of::<CodegenHeader>());\n\
+             }}\n"
+        ),
+    )
+    .unwrap();
+    let binary = directory.join("header");
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let output = Command::new(rustc)
+        .arg("--edition=2024")
+        .arg("-o")
+        .arg(&binary)
+        .arg(&source)
+        .output()
+        .expect("couldn't run rustc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let output = Command::new(&binary).output().unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), size.to_string());
+}
@@ -0,0 +1,141 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use object::{Object, ObjectSection};
+
+/// The path to one of the Elf files in `tests/fixtures`, which are built by
+/// `tests/fixtures/build.sh`.
+pub fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+/// Load the debug information from one of the fixtures.
+pub fn load(name: &str) -> tasru::DebugInfo {
+    tasru::DebugInfo::new(&fixture(name)).expect("couldn't load fixture")
+}
+
+#[derive(Debug)]
+pub struct Unmapped(pub u64);
+
+impl core::fmt::Display for Unmapped {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "nothing is mapped at {:#x}", self.0)
+    }
+}
+
+impl core::error::Error for Unmapped {}
+
+/// Memory made up of regions of bytes, which can be filled in from the initial
+/// contents of an Elf file and then modified by the test.
+#[derive(Default)]
+pub struct FakeReader {
+    regions: Vec<(u64, Vec<u8>)>,
+}
+
+impl FakeReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Memory holding the contents of every allocated section of a fixture.
+    pub fn from_fixture(name: &str) -> Self {
+        let data = std::fs::read(fixture(name)).expect("couldn't read fixture");
+        let file = object::File::parse(&*data).expect("couldn't parse fixture");
+        let mut reader = Self::new();
+        for section in file.sections() {
+            if section.address() == 0 {
+                continue;
+            }
+            if let Ok(contents) = section.data()
+                && !contents.is_empty()
+            {
+                reader.write(section.address(), contents);
+            }
+        }
+        reader
+    }
+
+    /// Place `bytes` at `address`, replacing whatever was mapped there before.
+    pub fn write(&mut self, address: u64, bytes: &[u8]) -> &mut Self {
+        self.regions.insert(0, (address, bytes.to_vec()));
+        self
+    }
+}
+
+impl tasru::memory::Read for FakeReader {
+    type Error = Unmapped;
+
+    fn read_u8(&mut self, address: u64) -> Result<u8, Self::Error> {
+        self.regions
+            .iter()
+            .find(|(start, bytes)| address >= *start && address - start < bytes.len() as u64)
+            .map(|(start, bytes)| bytes[(address - start) as usize])
+            .ok_or(Unmapped(address))
+    }
+}
+
+/// The static variable called `name`, ignoring its namespace.
+pub fn variable<'a>(
+    info: &'a tasru::DebugInfo,
+    name: &str,
+) -> tasru::debug_types::DebugVariable<'a> {
+    info.variables()
+        .find(|variable| variable.name().rsplit("::").next() == Some(name))
+        .unwrap_or_else(|| panic!("variable {name} not found"))
+}
+
+/// The address of `name` in the symbol table of a fixture.
+pub fn symbol_address(fixture_name: &str, name: &str) -> u64 {
+    use object::ObjectSymbol;
+
+    let data = std::fs::read(fixture(fixture_name)).expect("couldn't read fixture");
+    let file = object::File::parse(&*data).expect("couldn't parse fixture");
+    file.symbols()
+        .find(|symbol| symbol.name() == Ok(name))
+        .map(|symbol| symbol.address())
+        .unwrap_or_else(|| panic!("symbol {name} not found"))
+}
+
+/// Counts how often the memory behind it is accessed.
+pub struct CountingReader {
+    pub memory: FakeReader,
+    pub block_reads: usize,
+    pub byte_reads: usize,
+    /// How many bursts were started with `begin()` and ended with `finish()`.
+    pub bursts: usize,
+}
+
+impl CountingReader {
+    pub fn new(memory: FakeReader) -> Self {
+        CountingReader {
+            memory,
+            block_reads: 0,
+            byte_reads: 0,
+            bursts: 0,
+        }
+    }
+}
+
+impl tasru::memory::Read for CountingReader {
+    type Error = Unmapped;
+
+    fn read_u8(&mut self, address: u64) -> Result<u8, Self::Error> {
+        self.byte_reads += 1;
+        self.memory.read_u8(address)
+    }
+
+    fn read(&mut self, data: &mut [u8], address: u64) -> Result<(), Self::Error> {
+        self.block_reads += 1;
+        self.memory.read(data, address)
+    }
+
+    fn begin(&mut self) -> Result<(), Self::Error> {
+        self.bursts += 1;
+        Ok(())
+    }
+}
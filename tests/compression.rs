@@ -0,0 +1,49 @@
+#![cfg(feature = "compression")]
+
+mod common;
+
+use common::{load, variable};
+
+/// Everything that the tests compare between a fixture and its compressed twin: every
+/// variable with its address and size, and every structure with its size.
+fn symbol_map(info: &tasru::DebugInfo) -> Vec<String> {
+    let mut symbols: Vec<String> =
+        info.variables()
+            .map(|variable| {
+                format!(
+                    "{} @ {:?} ({:?} bytes)",
+                    variable.name(),
+                    variable.location(),
+                    variable.size()
+                )
+            })
+            .chain(info.structures().map(|structure| {
+                format!("struct {} ({:?} bytes)", structure.name(), structure.size())
+            }))
+            .collect();
+    symbols.sort();
+    symbols
+}
+
+#[test]
+fn uncompressed_fixture_has_symbols() {
+    let info = load("basic");
+    assert_eq!(info.variable_count(), 6);
+    assert!(variable(&info, "COUNTER").location().is_some());
+}
+
+#[test]
+fn zlib_gnu_matches_uncompressed() {
+    let plain = load("basic");
+    let compressed = load("basic-zlib-gnu");
+    assert_eq!(plain.statistics(), compressed.statistics());
+    assert_eq!(symbol_map(&plain), symbol_map(&compressed));
+}
+
+#[test]
+fn zstd_matches_uncompressed() {
+    let plain = load("basic");
+    let compressed = load("basic-zstd");
+    assert_eq!(plain.statistics(), compressed.statistics());
+    assert_eq!(symbol_map(&plain), symbol_map(&compressed));
+}
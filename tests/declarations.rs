@@ -0,0 +1,62 @@
+mod common;
+
+use common::{FakeReader, load, variable};
+
+// `struct foo` is only declared in the unit that defines `HOLDER`, and defined in
+// another unit. Which of the two a lookup comes across first depends on the order of a
+// hash map, which changes every time the Elf file is loaded, so each test loads it
+// several times.
+const ATTEMPTS: usize = 16;
+
+#[test]
+fn find_structure_by_name_skips_declarations() {
+    for _ in 0..ATTEMPTS {
+        let info = load("declaration");
+        let foo = info
+            .find_structure_by_name(|_, name| name == "foo")
+            .expect("foo not found");
+        assert!(!foo.is_declaration());
+        assert_eq!(foo.size(), Some(8));
+        assert_eq!(foo.member_count(), 2);
+    }
+}
+
+#[test]
+fn declaration_resolves_to_definition_in_other_unit() {
+    for _ in 0..ATTEMPTS {
+        let info = load("declaration");
+        let holder = variable(&info, "HOLDER").structure().unwrap();
+        let member = holder.member_named("pointer").unwrap();
+        let declaration = info
+            .pointer_from_item(member.kind())
+            .and_then(|pointer| pointer.kind())
+            .expect("pointer has no pointee");
+
+        assert_eq!(info.size_from_item(declaration).map(|size| size.0), Some(8));
+        let foo = info.structure_from_item(declaration).unwrap();
+        assert!(!foo.is_declaration());
+        assert_eq!(foo.size(), Some(8));
+        let foo = info
+            .structure_from_item_at_address(&declaration, 0x1000)
+            .unwrap();
+        assert_eq!(foo.members_bound().count(), 2);
+    }
+}
+
+#[test]
+fn members_of_declared_type_can_be_read() {
+    let info = load("declaration");
+    let mut memory = FakeReader::from_fixture("declaration");
+    let holder = variable(&info, "HOLDER").structure().unwrap();
+    let foo = holder
+        .member_named("pointer")
+        .unwrap()
+        .pointer()
+        .unwrap()
+        .follow(&mut memory)
+        .unwrap()
+        .structure()
+        .unwrap();
+    let y = foo.member_named("y").unwrap().base_type().unwrap();
+    assert_eq!(y.as_i32(&mut memory), Some(2));
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::{load, variable};
+
+#[test]
+fn c_names_resolve() {
+    // GCC names things with inline strings, `.debug_str` and `.debug_line_str`.
+    let info = load("basic-dwarf5");
+    for name in ["ORIGIN", "SENSOR", "COUNTER"] {
+        assert!(variable(&info, name).location().is_some());
+    }
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    assert_eq!(sensor.name(), "sensor");
+    let position = sensor
+        .member_named("position")
+        .unwrap()
+        .structure()
+        .unwrap();
+    assert_eq!(position.name(), "point");
+    assert!(position.member_named("x").is_ok());
+    assert!(position.member_named("y").is_ok());
+    assert!(
+        variable(&info, "COUNTER")
+            .file()
+            .is_some_and(|file| file.ends_with("basic.c"))
+    );
+}
+
+#[test]
+fn rust_names_resolve() {
+    // rustc names everything with indexed strings from `.debug_str_offsets`.
+    let info = load("dwarf5");
+    assert_eq!(variable(&info, "COUNTER").namespace(), "dwarf5");
+    assert_eq!(variable(&info, "ORIGIN").namespace(), "dwarf5");
+    let point = info.structure_from_type("dwarf5::Point").unwrap();
+    assert_eq!(point.members_bound().count(), 2);
+    assert!(point.member_named("x").is_ok());
+    assert!(point.member_named("y").is_ok());
+}
+
+#[test]
+fn indexed_addresses_match_symbol_table() {
+    // rustc describes the address of each static with `DW_OP_addrx`, an index into
+    // `.debug_addr`.
+    let info = load("dwarf5");
+    for name in ["COUNTER", "ORIGIN"] {
+        assert_eq!(
+            variable(&info, name).location().map(|location| location.0),
+            Some(common::symbol_address("dwarf5", name)),
+            "address of {name}"
+        );
+    }
+}
+
+#[test]
+fn direct_addresses_match_symbol_table() {
+    let info = load("basic-dwarf5");
+    for name in ["ORIGIN", "SENSOR", "COUNTER"] {
+        assert_eq!(
+            variable(&info, name).location().map(|location| location.0),
+            Some(common::symbol_address("basic-dwarf5", name)),
+            "address of {name}"
+        );
+    }
+}
+
+#[test]
+fn variables_in_file_match_whole_path_components() {
+    let info = load("basic-dwarf5");
+    let file = variable(&info, "COUNTER").file().unwrap().to_owned();
+    assert!(
+        info.variables_in_file(&file)
+            .any(|v| v.name().ends_with("COUNTER"))
+    );
+    assert!(
+        info.variables_in_file("basic.c")
+            .any(|v| v.name().ends_with("COUNTER"))
+    );
+    assert!(info.variables_in_file("src/basic.c").next().is_some());
+    assert!(info.variables_in_file("asic.c").next().is_none());
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::{FakeReader, load, variable};
+use gimli::RunTimeEndian;
+use tasru::memory::{EndiannessAwareReader, Read};
+
+// The same program built for little-endian and big-endian Arm, so that each value is
+// stored in the opposite byte order in the two fixtures.
+const FIXTURES: [(&str, RunTimeEndian); 2] = [
+    ("endian-little", RunTimeEndian::Little),
+    ("endian-big", RunTimeEndian::Big),
+];
+
+#[test]
+fn endianness_is_detected() {
+    for (fixture, endian) in FIXTURES {
+        assert_eq!(load(fixture).endian(), endian, "{fixture}");
+    }
+}
+
+#[test]
+fn base_types_are_read_in_target_order() {
+    for (fixture, _) in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let counter = variable(&info, "COUNTER").base_type().unwrap();
+        assert_eq!(counter.as_u32(&mut memory), Some(0x1234_5678), "{fixture}");
+        assert_eq!(counter.as_u64(&mut memory), Some(0x1234_5678), "{fixture}");
+
+        let origin = variable(&info, "ORIGIN").structure().unwrap();
+        let x = origin.member_named("x").unwrap().base_type().unwrap();
+        let y = origin.member_named("y").unwrap().base_type().unwrap();
+        assert_eq!(x.as_i16(&mut memory), Some(-3), "{fixture}");
+        assert_eq!(y.as_u16(&mut memory), Some(4), "{fixture}");
+    }
+}
+
+#[test]
+fn raw_bytes_are_assembled_in_target_order() {
+    for (fixture, endian) in FIXTURES {
+        let info = load(fixture);
+        let counter = variable(&info, "COUNTER");
+        let address = counter.location().unwrap().0;
+        let mut memory = FakeReader::new();
+        memory.write(address, &[0xde, 0xad, 0xbe, 0xef]);
+        let expected = match endian {
+            RunTimeEndian::Little => 0xefbe_adde,
+            RunTimeEndian::Big => 0xdead_beef,
+        };
+        let counter = counter.base_type().unwrap();
+        assert_eq!(counter.as_u32(&mut memory), Some(expected), "{fixture}");
+    }
+}
+
+#[test]
+fn discriminants_are_read_in_target_order() {
+    for (fixture, _) in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let mode = variable(&info, "MODE").enumeration().unwrap();
+        assert_eq!(
+            mode.variant(&mut memory).unwrap().name(),
+            "Busy",
+            "{fixture}"
+        );
+    }
+}
+
+#[test]
+fn pointers_are_followed_in_target_order() {
+    for (fixture, _) in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let counter = variable(&info, "HOLDER")
+            .structure()
+            .unwrap()
+            .member_named("counter")
+            .unwrap()
+            .pointer()
+            .unwrap()
+            .follow(&mut memory)
+            .unwrap()
+            .base_type()
+            .unwrap();
+        assert_eq!(counter.as_u32(&mut memory), Some(0x1234_5678), "{fixture}");
+    }
+}
+
+#[test]
+fn endianness_aware_reader_passes_big_endian_reads_through() {
+    let mut memory = FakeReader::new();
+    memory.write(0x1000, &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+    for endian in [RunTimeEndian::Little, RunTimeEndian::Big] {
+        let mut reader = EndiannessAwareReader::new(&mut memory, endian);
+        assert_eq!(reader.read_u16_be(0x1000).unwrap(), 0x0102);
+        assert_eq!(reader.read_u32_be(0x1000).unwrap(), 0x0102_0304);
+        assert_eq!(reader.read_u64_be(0x1000).unwrap(), 0x0102_0304_0506_0708);
+    }
+    let mut reader = EndiannessAwareReader::new(&mut memory, RunTimeEndian::Big);
+    assert_eq!(reader.read_u32(0x1000).unwrap(), 0x0102_0304);
+}
+
+#[test]
+fn endianness_aware_reader_works_with_debug_types() {
+    for (fixture, endian) in FIXTURES {
+        let info = load(fixture);
+        let mut memory = EndiannessAwareReader::new(FakeReader::from_fixture(fixture), endian);
+        let counter = variable(&info, "COUNTER").base_type().unwrap();
+        assert_eq!(counter.as_u32(&mut memory), Some(0x1234_5678), "{fixture}");
+        let mode = variable(&info, "MODE").enumeration().unwrap();
+        assert_eq!(
+            mode.variant(&mut memory).unwrap().name(),
+            "Busy",
+            "{fixture}"
+        );
+    }
+}
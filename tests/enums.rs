@@ -0,0 +1,124 @@
+mod common;
+
+use common::{FakeReader, load, variable};
+
+#[test]
+fn signed_discriminants_are_sign_extended() {
+    let info = load("enums");
+    let mut memory = FakeReader::from_fixture("enums");
+
+    let negative = variable(&info, "NEGATIVE").enumeration().unwrap();
+    let variant = negative.variant(&mut memory).unwrap();
+    assert_eq!(variant.name(), "Neg");
+    assert_eq!(variant.discriminant(), Some(-1));
+
+    let zero = variable(&info, "ZERO").enumeration().unwrap();
+    assert_eq!(zero.variant(&mut memory).unwrap().name(), "Zero");
+    assert_eq!(
+        negative.variant_named("Neg").unwrap().discriminant(),
+        Some(-1)
+    );
+}
+
+#[test]
+fn negative_discriminant_is_not_read_as_255() {
+    let info = load("enums");
+    let negative = variable(&info, "NEGATIVE");
+    let address = negative.location().unwrap().0;
+    let mut memory = FakeReader::new();
+    memory.write(address, &[0xff]);
+    let variant = negative
+        .enumeration()
+        .unwrap()
+        .variant(&mut memory)
+        .unwrap();
+    assert_eq!(variant.name(), "Neg");
+}
+
+#[test]
+fn wide_discriminants_are_read() {
+    let info = load("enums");
+    let mut memory = FakeReader::from_fixture("enums");
+    let wide = variable(&info, "WIDE").enumeration().unwrap();
+    let variant = wide.variant(&mut memory).unwrap();
+    assert_eq!(variant.name(), "Large");
+    assert_eq!(
+        variant.discriminant(),
+        Some(0x1234_5678_9abc_def0_0fed_cba9_8765_4321)
+    );
+}
+
+#[test]
+fn variant_accessors_look_through_the_payload() {
+    let info = load("enums");
+    let mut memory = FakeReader::from_fixture("enums");
+
+    let number = variable(&info, "SOME_NUMBER").enumeration().unwrap();
+    let some = number.variant(&mut memory).unwrap();
+    assert_eq!(some.name(), "Some");
+    assert_eq!(
+        some.base_type().unwrap().as_u32(&mut memory),
+        Some(0x0bad_cafe)
+    );
+
+    let pointer = variable(&info, "SOME_POINTER").enumeration().unwrap();
+    let target = pointer
+        .variant(&mut memory)
+        .unwrap()
+        .pointer()
+        .unwrap()
+        .follow(&mut memory)
+        .unwrap();
+    assert_eq!(
+        target.location().unwrap(),
+        common::symbol_address("enums", "TARGET")
+    );
+    assert_eq!(
+        target.base_type().unwrap().as_u32(&mut memory),
+        Some(0x1234_5678)
+    );
+
+    let inner = variable(&info, "SOME_ENUM").enumeration().unwrap();
+    let inner = inner.variant(&mut memory).unwrap().enumeration().unwrap();
+    assert_eq!(inner.variant(&mut memory).unwrap().name(), "Neg");
+
+    let array = variable(&info, "SOME_ARRAY").enumeration().unwrap();
+    let array = array.variant(&mut memory).unwrap().array().unwrap();
+    let values: Vec<_> = (0..3)
+        .map(|index| {
+            array
+                .get(index)
+                .unwrap()
+                .base_type()
+                .unwrap()
+                .as_u16(&mut memory)
+        })
+        .collect();
+    assert_eq!(values, [Some(1), Some(2), Some(3)]);
+}
+
+#[test]
+fn slice_of_enumerations_counts_down() {
+    let info = load("enums");
+    let mut memory = FakeReader::from_fixture("enums");
+    let signs = variable(&info, "SIGNS").structure().unwrap();
+    let mut signs = signs
+        .as_slice(&mut memory)
+        .unwrap()
+        .enumeration_iter()
+        .unwrap();
+    assert_eq!(signs.len(), 3);
+    let first = signs.next().unwrap().unwrap();
+    assert_eq!(first.variant(&mut memory).unwrap().name(), "Neg");
+    assert_eq!(signs.len(), 2);
+    let names: Vec<_> = signs
+        .map(|sign| {
+            sign.unwrap()
+                .variant(&mut memory)
+                .unwrap()
+                .name()
+                .to_owned()
+        })
+        .collect();
+    assert_eq!(names, ["Zero", "Neg"]);
+}
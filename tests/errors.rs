@@ -0,0 +1,43 @@
+mod common;
+
+use common::load;
+
+#[test]
+fn lookups_at_an_address_name_it_once() {
+    let info = load("basic");
+    let error = info
+        .structure_from_type_at_address("missing", 0x1000)
+        .unwrap_err();
+    assert_eq!(error.location(), Some(0x1000));
+    assert_eq!(
+        error.to_string(),
+        "Structure for \"missing\" could not be found (at 0x1000)"
+    );
+    let error = info
+        .enumeration_from_type_at_address("missing", 0x1000)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Enumeration for \"missing\" could not be found (at 0x1000)"
+    );
+    let error = info
+        .union_from_type_at_address("missing", 0x1000)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Union \"missing\" could not be found (at 0x1000)"
+    );
+}
+
+#[test]
+fn offset_of_only_accepts_structures_and_unions() {
+    let info = load("basic");
+    assert_eq!(info.offset_of("sensor", "position.y").unwrap(), 10);
+
+    let info = load("enums");
+    let error = info.offset_of("Signed", "0").unwrap_err();
+    assert!(matches!(
+        error,
+        tasru::debug_types::DebugTypeError::StructureNotFound { .. }
+    ));
+}
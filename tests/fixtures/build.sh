@@ -0,0 +1,42 @@
+#!/bin/sh
+# Rebuild the Elf files used by the tests from the sources in `src`. The results
+# are checked in, so this only needs to be run when a source file changes.
+set -e
+cd "$(dirname "$0")"
+
+CFLAGS="-g -O0 -nostdlib -static -no-pie -fno-pie -fno-asynchronous-unwind-tables -Wl,--build-id=none"
+
+gcc $CFLAGS -gdwarf-4 -o basic src/basic.c
+objcopy --compress-debug-sections=zlib-gnu basic basic-zlib-gnu
+objcopy --compress-debug-sections=zstd basic basic-zstd
+
+gcc $CFLAGS -gdwarf-4 -o declaration src/declaration_a.c src/declaration_b.c
+gcc $CFLAGS -gdwarf-5 -o basic-dwarf5 src/basic.c
+gcc $CFLAGS -gdwarf-4 -o overlay src/overlay.c \
+    -Wl,--section-start=.outer=0x500000 -Wl,--section-start=.inner=0x500010 \
+    -Wl,--no-check-sections
+gcc $CFLAGS -gdwarf-4 -o vectors src/vectors.c -Wl,--section-start=.vectors=0
+g++ $CFLAGS -gdwarf-4 -o specification src/specification.cpp
+g++ $CFLAGS -gdwarf-4 -o templates src/templates.cpp
+
+RUSTFLAGS="-g -C opt-level=0 -C panic=abort -C relocation-model=static -C link-arg=-nostartfiles -C link-arg=-static --crate-type bin"
+
+rustc $RUSTFLAGS -C dwarf-version=5 -o dwarf5 src/dwarf5.rs
+rustc $RUSTFLAGS -o enums src/enums.rs
+rustc $RUSTFLAGS -o codegen src/codegen.rs
+
+NIGHTLY_RUSTFLAGS="-g -C opt-level=0 -C panic=abort --crate-type bin"
+
+rustc +nightly $NIGHTLY_RUSTFLAGS --target armv7r-none-eabi -o endian-little src/endian.rs
+rustc +nightly $NIGHTLY_RUSTFLAGS --target armebv7r-none-eabi -o endian-big src/endian.rs
+
+# `String` is defined by `alloc`, whose debug information would make a linked binary
+# huge, so these are built as libraries and linked without it. The layout of `String`
+# depends on the compiler version, so build with both toolchains.
+for toolchain in stable nightly; do
+    rustc +$toolchain -g -C opt-level=0 -C panic=abort -C relocation-model=static \
+        --crate-type lib --emit obj -o strings-$toolchain.o src/strings.rs
+    ld -static -e _start --build-id=none --gc-sections -u GREETING -u OWNED \
+        -o strings-$toolchain strings-$toolchain.o
+    rm strings-$toolchain.o
+done
@@ -0,0 +1,30 @@
+/* A small C program whose debug information is used by the tests. */
+#include <stdint.h>
+
+struct point {
+    int16_t x;
+    int16_t y;
+};
+
+struct sensor {
+    const char *name;
+    struct point position;
+    uint8_t flags;
+    int32_t reading;
+};
+
+typedef struct {
+    uint16_t id;
+} tag_t;
+
+struct point ORIGIN = {-3, 4};
+struct sensor SENSOR = {"thermo", {10, -20}, 0xff, -40};
+uint32_t COUNTER = 0x12345678;
+const tag_t TAG = {7};
+uint8_t BYTES[4] = {1, 2, 3, 0xff};
+uint32_t WORDS[2] = {0x12345678, 0x9abcdef0};
+
+void _start(void) {
+    for (;;) {
+    }
+}
@@ -0,0 +1,64 @@
+// A `repr(C)` structure with members of every kind that the C and Rust generators
+// handle, including pointers to `()` and to an enumeration with fields.
+#![no_std]
+#![no_main]
+
+use core::marker::PhantomData;
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+pub enum Shape {
+    Circle(u32),
+    Square(u16),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Word {
+    pub bytes: [u8; 4],
+    pub value: u32,
+}
+
+#[repr(C)]
+pub struct Header {
+    pub magic: u32,
+    pub flags: u8,
+    pub mode: Mode,
+    pub values: [u16; 3],
+    pub word: Word,
+    pub context: *const (),
+    pub shape: *const Shape,
+    pub next: *const Header,
+    pub marker: PhantomData<u8>,
+}
+
+pub static SHAPE: Shape = Shape::Circle(3);
+
+#[used]
+#[unsafe(no_mangle)]
+pub static mut HEADER: Header = Header {
+    magic: 0x4844_5221,
+    flags: 0x80,
+    mode: Mode::On,
+    values: [1, 2, 3],
+    word: Word { value: 7 },
+    context: core::ptr::null(),
+    shape: &SHAPE,
+    next: core::ptr::null(),
+    marker: PhantomData,
+};
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,15 @@
+/* `struct foo` is only declared here, and defined in declaration_b.c. */
+struct foo;
+
+struct holder {
+    struct foo *pointer;
+    int count;
+};
+
+extern struct foo FOO;
+struct holder HOLDER = {&FOO, 3};
+
+void _start(void) {
+    for (;;) {
+    }
+}
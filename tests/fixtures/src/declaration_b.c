@@ -0,0 +1,7 @@
+/* The definition of the structure that declaration_a.c only declares. */
+struct foo {
+    int x;
+    int y;
+};
+
+struct foo FOO = {1, 2};
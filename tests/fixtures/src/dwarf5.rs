@@ -0,0 +1,26 @@
+// Built with DWARF 5, so names are indexed strings and addresses are indexed through
+// `.debug_addr`.
+#![no_std]
+#![no_main]
+
+pub struct Point {
+    pub x: i16,
+    pub y: i16,
+}
+
+#[used]
+#[unsafe(no_mangle)]
+pub static mut ORIGIN: Point = Point { x: -3, y: 4 };
+#[used]
+#[unsafe(no_mangle)]
+pub static COUNTER: u32 = 0x1234_5678;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
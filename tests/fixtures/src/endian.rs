@@ -0,0 +1,62 @@
+// Built for both little-endian and big-endian Arm. Neither target has a prebuilt
+// `core`, so this defines the few language items it needs itself, which requires a
+// nightly compiler.
+#![feature(no_core, lang_items, auto_traits)]
+#![allow(internal_features)]
+#![no_core]
+#![no_std]
+#![no_main]
+
+#[lang = "pointee_sized"]
+pub trait PointeeSized {}
+#[lang = "meta_sized"]
+pub trait MetaSized: PointeeSized {}
+#[lang = "sized"]
+pub trait Sized: MetaSized {}
+#[lang = "copy"]
+pub trait Copy {}
+#[lang = "freeze"]
+pub unsafe auto trait Freeze {}
+#[lang = "sync"]
+pub unsafe trait Sync {}
+#[lang = "drop_glue"]
+pub unsafe fn drop_in_place<T: PointeeSized>(_: *mut T) {}
+
+unsafe impl Sync for u32 {}
+unsafe impl Sync for Point {}
+unsafe impl Sync for Mode {}
+unsafe impl Sync for Holder {}
+
+pub struct Point {
+    pub x: i16,
+    pub y: i16,
+}
+
+pub struct Holder {
+    pub counter: &'static u32,
+}
+
+#[repr(u16)]
+pub enum Mode {
+    Idle = 0x0001,
+    Busy = 0x0102,
+}
+
+#[used]
+#[unsafe(no_mangle)]
+pub static ORIGIN: Point = Point {
+    x: 0xfffd_u16 as i16,
+    y: 4,
+};
+#[used]
+#[unsafe(no_mangle)]
+pub static COUNTER: u32 = 0x1234_5678;
+#[used]
+#[unsafe(no_mangle)]
+pub static MODE: Mode = Mode::Busy;
+#[used]
+#[unsafe(no_mangle)]
+pub static HOLDER: Holder = Holder { counter: &COUNTER };
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() {}
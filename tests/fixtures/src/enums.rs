@@ -0,0 +1,53 @@
+// Enumerations with signed and 128-bit discriminants, and variants that hold values.
+#![no_std]
+#![no_main]
+
+#[repr(i8)]
+pub enum Signed {
+    Neg = -1,
+    Zero = 0,
+}
+
+#[repr(u128)]
+pub enum Wide {
+    Small = 1,
+    Large = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321,
+}
+
+#[used]
+#[unsafe(no_mangle)]
+pub static NEGATIVE: Signed = Signed::Neg;
+#[used]
+#[unsafe(no_mangle)]
+pub static ZERO: Signed = Signed::Zero;
+#[used]
+#[unsafe(no_mangle)]
+pub static WIDE: Wide = Wide::Large;
+#[used]
+#[unsafe(no_mangle)]
+pub static TARGET: u32 = 0x1234_5678;
+#[used]
+#[unsafe(no_mangle)]
+pub static SOME_NUMBER: Option<u32> = Some(0x0bad_cafe);
+#[used]
+#[unsafe(no_mangle)]
+pub static SOME_POINTER: Option<&u32> = Some(&TARGET);
+#[used]
+#[unsafe(no_mangle)]
+pub static SOME_ENUM: Option<Signed> = Some(Signed::Neg);
+#[used]
+#[unsafe(no_mangle)]
+pub static SOME_ARRAY: Option<[u16; 3]> = Some([1, 2, 3]);
+#[used]
+#[unsafe(no_mangle)]
+pub static SIGNS: &[Signed] = &[Signed::Neg, Signed::Zero, Signed::Neg];
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,11 @@
+/* Two variables in sections that overlap, as they would in an overlay, so that `INNER`
+   lies inside `OUTER`. */
+#include <stdint.h>
+
+uint8_t OUTER[64] __attribute__((section(".outer"))) = {1};
+uint32_t INNER __attribute__((section(".inner"))) = 2;
+
+void _start(void) {
+    for (;;) {
+    }
+}
@@ -0,0 +1,18 @@
+// Variables that are declared inside a namespace and defined outside of it, so their
+// definitions refer to the declarations with `DW_AT_specification`.
+namespace outer {
+namespace inner {
+extern int counter;
+struct Limits {
+    static int maximum;
+};
+} // namespace inner
+} // namespace outer
+
+int outer::inner::counter = 3;
+int outer::inner::Limits::maximum = 5;
+
+extern "C" void _start() {
+    for (;;) {
+    }
+}
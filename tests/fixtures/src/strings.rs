@@ -0,0 +1,20 @@
+// Strings, whose owned form is laid out differently by different compiler versions.
+// This is built as a library and linked by hand, as linking a binary against `alloc`
+// would bring along all of its debug information.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+#[used]
+#[unsafe(no_mangle)]
+pub static GREETING: &str = "hello, world";
+#[used]
+#[unsafe(no_mangle)]
+pub static mut OWNED: String = String::new();
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
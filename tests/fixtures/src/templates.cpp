@@ -0,0 +1,22 @@
+// C++ templates, whose parameters hang off `DW_TAG_class_type` and
+// `DW_TAG_union_type` rather than `DW_TAG_structure_type`.
+template <typename T, int N>
+class Buffer {
+public:
+    T items[N];
+    int count;
+};
+
+template <typename T>
+union Either {
+    T value;
+    unsigned char bytes[sizeof(T)];
+};
+
+Buffer<short, 4> BUFFER = {{1, 2, 3, 4}, 4};
+Either<unsigned int> EITHER = {0x12345678};
+
+extern "C" void _start() {
+    for (;;) {
+    }
+}
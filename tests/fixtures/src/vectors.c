@@ -0,0 +1,11 @@
+/* A table at address 0, where the vector table of a microcontroller usually lives. */
+#include <stdint.h>
+
+const uint32_t VECTORS[4] __attribute__((section(".vectors"), used)) = {
+    0x20001000, 0x101, 0x103, 0x105};
+uint32_t TICKS = 1;
+
+void _start(void) {
+    for (;;) {
+    }
+}
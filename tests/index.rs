@@ -0,0 +1,51 @@
+#![cfg(feature = "index")]
+mod common;
+
+use common::{fixture, load, variable};
+use tasru::{DebugInfo, DebugInfoError};
+
+/// A path in the temporary directory that is unique to this test.
+fn index_path(test: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("tasru-{}-{test}.index", std::process::id()))
+}
+
+#[test]
+fn index_round_trips() {
+    let path = index_path("round-trip");
+    load("basic").save_index(&path).unwrap();
+    let elf = std::fs::read(fixture("basic")).unwrap();
+    let info = DebugInfo::load_index_for(&path, &elf).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(info.statistics(), load("basic").statistics());
+    assert_eq!(
+        variable(&info, "COUNTER").location(),
+        variable(&load("basic"), "COUNTER").location()
+    );
+}
+
+#[test]
+fn index_for_another_elf_is_stale() {
+    let path = index_path("stale");
+    load("basic").save_index(&path).unwrap();
+    let mut elf = std::fs::read(fixture("basic")).unwrap();
+    // Changing a single byte anywhere must be noticed.
+    for position in [0, elf.len() / 2, elf.len() - 1] {
+        elf[position] ^= 1;
+        assert!(matches!(
+            DebugInfo::load_index_for(&path, &elf),
+            Err(DebugInfoError::IndexStale)
+        ));
+        elf[position] ^= 1;
+    }
+    // So must bytes after the last whole word.
+    elf.push(0);
+    assert!(matches!(
+        DebugInfo::load_index_for(&path, &elf),
+        Err(DebugInfoError::IndexStale)
+    ));
+    // `load_index` has nothing to compare the checksum with.
+    #[allow(deprecated)]
+    let unchecked = DebugInfo::load_index(&path);
+    assert!(unchecked.is_ok());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::{CountingReader, FakeReader};
+use tasru::memory::{BufferedReader, Read};
+
+#[test]
+fn buffered_reads_fetch_each_block_once() {
+    let mut memory = FakeReader::new();
+    memory.write(0x1000, &[0x11; 64]);
+    let mut reader = BufferedReader::<_, 16>::new(CountingReader::new(memory));
+    for address in 0x1000..0x1020 {
+        assert_eq!(reader.read_u8(address).unwrap(), 0x11);
+    }
+    let inner = reader.into_inner();
+    assert_eq!(inner.block_reads, 2);
+    assert_eq!(inner.byte_reads, 0);
+}
+
+#[test]
+fn unreadable_block_is_only_fetched_once() {
+    // The block at 0x1000 extends past the end of the mapped memory, so it can't be
+    // fetched as a whole and its bytes are read one at a time.
+    let mut memory = FakeReader::new();
+    memory.write(0x1000, &[0x22; 8]);
+    let mut reader = BufferedReader::<_, 16>::new(CountingReader::new(memory));
+    for address in 0x1000..0x1008 {
+        assert_eq!(reader.read_u8(address).unwrap(), 0x22);
+    }
+    assert!(reader.read_u8(0x1008).is_err());
+    let inner = reader.into_inner();
+    assert_eq!(inner.block_reads, 1);
+    assert_eq!(inner.byte_reads, 9);
+}
+
+#[test]
+fn begin_retries_unreadable_block() {
+    let mut memory = FakeReader::new();
+    memory.write(0x1000, &[0x33; 8]);
+    let mut reader = BufferedReader::<_, 16>::new(CountingReader::new(memory));
+    assert_eq!(reader.read_u8(0x1000).unwrap(), 0x33);
+    reader.begin().unwrap();
+    assert_eq!(reader.read_u8(0x1001).unwrap(), 0x33);
+    assert_eq!(reader.into_inner().block_reads, 2);
+}
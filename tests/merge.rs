@@ -0,0 +1,54 @@
+mod common;
+
+use common::{load, variable};
+
+#[test]
+fn merged_files_resolve_types_from_each() {
+    let mut info = load("basic");
+    info.extend(load("enums")).unwrap();
+
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    assert_eq!(sensor.name(), "sensor");
+    let position = sensor
+        .member_named("position")
+        .unwrap()
+        .structure()
+        .unwrap();
+    assert_eq!(position.name(), "point");
+    assert_eq!(
+        position
+            .member_named("x")
+            .unwrap()
+            .base_type()
+            .unwrap()
+            .size(),
+        2
+    );
+
+    let negative = variable(&info, "NEGATIVE").enumeration().unwrap();
+    assert_eq!(negative.name(), "Signed");
+    let target = variable(&info, "TARGET").base_type().unwrap();
+    assert_eq!(target.name(), "u32");
+    assert_eq!(
+        info.layout_of("enums::Signed").unwrap(),
+        load("enums").layout_of("enums::Signed").unwrap()
+    );
+}
+
+#[test]
+fn merging_twice_keeps_the_files_apart() {
+    let info = tasru::DebugInfo::merge(load("basic"), load("basic")).unwrap();
+    let info = tasru::DebugInfo::merge(info, load("enums")).unwrap();
+    assert_eq!(
+        info.find_variables(|variable| variable.name() == "::SENSOR")
+            .count(),
+        1
+    );
+    assert_eq!(
+        variable(&info, "SOME_NUMBER")
+            .enumeration()
+            .unwrap()
+            .variant_count(),
+        2
+    );
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::{load, variable};
+
+#[test]
+fn definitions_take_namespace_from_specification() {
+    // Both variables are defined at the top level of the file, outside of the namespace
+    // that declares them.
+    let info = load("specification");
+    let counter = variable(&info, "counter");
+    assert_eq!(counter.namespace(), "outer::inner");
+    assert_eq!(counter.name(), "outer::inner::counter");
+    assert_eq!(variable(&info, "maximum").namespace(), "outer::inner");
+}
+
+#[test]
+fn typedefs_name_anonymous_structures() {
+    // `const tag_t TAG`, where `tag_t` is a typedef of an anonymous structure.
+    let info = load("basic");
+    let tag = variable(&info, "TAG");
+    assert_eq!(
+        info.full_qualified_name_from_item(tag.kind()).as_deref(),
+        Some("tag_t")
+    );
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let reading = sensor.member_named("reading").unwrap();
+    assert_eq!(
+        info.full_qualified_name_from_item(reading.kind())
+            .as_deref(),
+        Some("int32_t")
+    );
+    assert_eq!(
+        info.full_qualified_name_from_item(sensor.kind()).as_deref(),
+        Some("sensor")
+    );
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::{CountingReader, FakeReader, load, variable};
+
+#[test]
+fn follows_64_bit_pointer_above_4_gib() {
+    const TARGET: u64 = 0x1_2345_6780;
+    let info = load("basic");
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let name = || sensor.member_named("name").unwrap().pointer().unwrap();
+    let mut memory = FakeReader::new();
+    memory.write(name().location().unwrap(), &TARGET.to_le_bytes());
+    memory.write(TARGET, b"x");
+
+    assert_eq!(name().target_address(&mut memory).unwrap(), TARGET);
+    let followed = name().follow(&mut memory).unwrap();
+    assert_eq!(followed.location().unwrap(), TARGET);
+    assert_eq!(followed.base_type().unwrap().as_u8(&mut memory), Some(b'x'));
+    let followed = name().try_follow(&mut memory).unwrap().unwrap();
+    assert_eq!(followed.location().unwrap(), TARGET);
+    let followed = name().follow_unless_null(&mut memory).unwrap();
+    assert_eq!(followed.location().unwrap(), TARGET);
+}
+
+#[test]
+fn pointers_are_read_once_when_followed() {
+    let info = load("basic");
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let name = || sensor.member_named("name").unwrap().pointer().unwrap();
+    let reads = |memory: &CountingReader| memory.byte_reads + memory.block_reads;
+
+    let mut memory = CountingReader::new(FakeReader::from_fixture("basic"));
+    name().target_address(&mut memory).unwrap();
+    let single = reads(&memory);
+    assert!(single > 0);
+
+    let mut memory = CountingReader::new(FakeReader::from_fixture("basic"));
+    name().try_follow(&mut memory).unwrap().unwrap();
+    assert_eq!(reads(&memory), single);
+
+    let mut memory = CountingReader::new(FakeReader::from_fixture("basic"));
+    name().follow_unless_null(&mut memory).unwrap();
+    assert_eq!(reads(&memory), single);
+}
+
+#[test]
+fn pointer_to_the_wrong_kind_says_what_it_points_to() {
+    let info = load("basic");
+    let sensor = variable(&info, "SENSOR").structure().unwrap();
+    let name = sensor.member_named("name").unwrap().pointer().unwrap();
+    for error in [name.structure().unwrap_err(), name.union().unwrap_err()] {
+        assert!(
+            matches!(
+                &error,
+                tasru::debug_types::DebugTypeError::KindIncorrect { actual, .. }
+                    if &**actual == "base type"
+            ),
+            "{error:?}"
+        );
+    }
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::load;
+
+#[test]
+fn sections_at_address_zero_are_allocated() {
+    let info = load("vectors");
+    let sections: Vec<_> = info
+        .sections()
+        .iter()
+        .map(|section| (section.name.as_str(), section.address))
+        .collect();
+    assert!(sections.contains(&(".vectors", 0)), "{sections:?}");
+    assert!(!sections.iter().any(|(name, _)| name.starts_with(".debug")));
+
+    let rows = info.static_size_report();
+    let ticks = rows.iter().find(|row| row.name == "TICKS").unwrap();
+    assert_eq!(ticks.section.as_deref(), Some(".data"));
+}
+
+#[test]
+fn report_rows_name_variables_without_their_namespace() {
+    let info = load("enums");
+    let rows = info.static_size_report();
+    let target = rows.iter().find(|row| row.name == "TARGET").unwrap();
+    assert_eq!(target.namespace, "enums");
+    assert_eq!(target.crate_name(), "enums");
+
+    let info = load("basic");
+    let rows = info.static_size_report();
+    let counter = rows.iter().find(|row| row.name == "COUNTER").unwrap();
+    assert_eq!(counter.namespace, "");
+    assert_eq!(counter.section.as_deref(), Some(".data"));
+}
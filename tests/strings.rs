@@ -0,0 +1,180 @@
+mod common;
+
+use common::{CountingReader, FakeReader, load, variable};
+use tasru::debug_types::DebugTypeError;
+
+/// The layout of `String` differs between compiler versions, so every test is run
+/// against a fixture from each.
+const FIXTURES: [&str; 2] = ["strings-stable", "strings-nightly"];
+
+/// Where the contents of strings written by `write_string` are put.
+const DATA: u64 = 0x10_0000;
+
+/// Write a `String` holding `contents` over `OWNED`. Every word but the length is set
+/// to the address of the data, so this doesn't depend on where the pointer and the
+/// capacity are within the structure.
+fn write_string(info: &tasru::DebugInfo, memory: &mut FakeReader, contents: &[u8]) {
+    let owned = variable(info, "OWNED");
+    let address = owned.location().unwrap().0;
+    for word in 0..3 {
+        memory.write(address + word * 8, &DATA.to_le_bytes());
+    }
+    let length = info.offset_of("alloc::string::String", "vec.len").unwrap();
+    memory.write(address + length, &(contents.len() as u64).to_le_bytes());
+    memory.write(DATA, contents);
+}
+
+#[test]
+fn reads_str() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let greeting = variable(&info, "GREETING").structure().unwrap();
+        assert_eq!(
+            greeting.as_rust_string(&mut memory, 64).unwrap(),
+            "hello, world",
+            "{fixture}"
+        );
+    }
+}
+
+#[test]
+fn reads_string() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let owned = variable(&info, "OWNED").structure().unwrap();
+        assert_eq!(
+            owned.as_rust_string(&mut memory, 64).unwrap(),
+            "",
+            "{fixture}"
+        );
+
+        write_string(&info, &mut memory, b"tasru");
+        assert_eq!(
+            owned.as_rust_string(&mut memory, 64).unwrap(),
+            "tasru",
+            "{fixture}"
+        );
+    }
+}
+
+#[test]
+fn replaces_invalid_utf8() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let owned = variable(&info, "OWNED").structure().unwrap();
+        write_string(&info, &mut memory, b"tas\xffru");
+        assert_eq!(
+            owned.as_rust_string(&mut memory, 64).unwrap(),
+            "tas\u{fffd}ru",
+            "{fixture}"
+        );
+    }
+}
+
+#[test]
+fn rejects_strings_over_the_maximum_length() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let greeting = variable(&info, "GREETING").structure().unwrap();
+        assert!(
+            matches!(
+                greeting.as_rust_string(&mut memory, 5),
+                Err(DebugTypeError::SizeError(12))
+            ),
+            "{fixture}"
+        );
+
+        let owned = variable(&info, "OWNED").structure().unwrap();
+        write_string(&info, &mut memory, b"tasru");
+        assert!(
+            matches!(
+                owned.as_rust_string(&mut memory, 4),
+                Err(DebugTypeError::SizeError(5))
+            ),
+            "{fixture}"
+        );
+    }
+}
+
+#[test]
+fn str_slices_are_read_with_a_length_limit() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let greeting = variable(&info, "GREETING").structure().unwrap();
+        let slice = greeting.as_slice(&mut memory).unwrap();
+        assert_eq!(slice.read_as_str(&mut memory).unwrap(), "hello, world");
+        assert_eq!(
+            slice.as_string_lossy(&mut memory, 12).unwrap(),
+            "hello, world"
+        );
+        assert!(matches!(
+            slice.as_string_lossy(&mut memory, 11),
+            Err(DebugTypeError::SizeError(12))
+        ));
+    }
+}
+
+#[test]
+fn str_slices_are_read_in_one_burst() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = CountingReader::new(FakeReader::from_fixture(fixture));
+        let greeting = variable(&info, "GREETING").structure().unwrap();
+        let slice = greeting.as_slice(&mut memory).unwrap();
+        let (bursts, block_reads) = (memory.bursts, memory.block_reads);
+        assert_eq!(slice.read_as_str(&mut memory).unwrap(), "hello, world");
+        assert_eq!(memory.bursts, bursts + 1, "{fixture}");
+        assert_eq!(memory.block_reads, block_reads + 1, "{fixture}");
+    }
+}
+
+#[test]
+fn str_slices_reject_garbage_lengths_and_null_pointers() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        let greeting = variable(&info, "GREETING");
+        let address = greeting.location().unwrap().0;
+        let greeting = greeting.structure().unwrap();
+        let length = info.offset_of("&str", "length").unwrap();
+        let data_ptr = info.offset_of("&str", "data_ptr").unwrap();
+
+        memory.write(address + length, &u64::MAX.to_le_bytes());
+        let slice = greeting.as_slice(&mut memory).unwrap();
+        assert!(
+            matches!(
+                slice.read_as_str(&mut memory),
+                Err(DebugTypeError::SizeError(u64::MAX))
+            ),
+            "{fixture}"
+        );
+
+        memory.write(address + length, &4u64.to_le_bytes());
+        memory.write(address + data_ptr, &0u64.to_le_bytes());
+        assert!(
+            matches!(
+                greeting.as_rust_string(&mut memory, 64),
+                Err(DebugTypeError::NullPointer { .. })
+            ),
+            "{fixture}"
+        );
+    }
+}
+
+#[test]
+fn rust_strings_are_read_in_one_burst() {
+    for fixture in FIXTURES {
+        let info = load(fixture);
+        let mut memory = FakeReader::from_fixture(fixture);
+        write_string(&info, &mut memory, b"tasru");
+        let mut memory = CountingReader::new(memory);
+        let owned = variable(&info, "OWNED").structure().unwrap();
+        assert_eq!(owned.as_rust_string(&mut memory, 64).unwrap(), "tasru");
+        assert_eq!(memory.bursts, 1, "{fixture}");
+    }
+}
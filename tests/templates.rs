@@ -0,0 +1,43 @@
+mod common;
+
+use common::{load, variable};
+use tasru::unit_info::ConstValue;
+
+#[test]
+fn classes_are_read_as_structures() {
+    let info = load("templates");
+    let buffer = variable(&info, "BUFFER").structure().unwrap();
+    assert_eq!(buffer.structure().name(), "Buffer<short int, 4>");
+    assert!(buffer.member_named("items").is_ok());
+    assert!(buffer.member_named("count").is_ok());
+}
+
+#[test]
+fn classes_and_unions_keep_their_template_parameters() {
+    let info = load("templates");
+
+    let buffer = info
+        .structure_from_item(variable(&info, "BUFFER").kind())
+        .unwrap();
+    let t = buffer.generic_named("T").unwrap();
+    assert_eq!(
+        info.full_qualified_name_from_item(t.kind()).as_deref(),
+        Some("short int")
+    );
+    assert!(t.value().is_none());
+    let n = buffer.generic_named("N").unwrap();
+    assert!(matches!(
+        n.value(),
+        Some(ConstValue::Fixed { value: 4, .. } | ConstValue::Unsigned(4) | ConstValue::Signed(4))
+    ));
+
+    let either = info
+        .union_from_item(variable(&info, "EITHER").kind())
+        .unwrap();
+    assert_eq!(either.generics().len(), 1);
+    assert_eq!(
+        info.full_qualified_name_from_item(either.generic_named("T").unwrap().kind())
+            .as_deref(),
+        Some("unsigned int")
+    );
+}
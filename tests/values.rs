@@ -0,0 +1,36 @@
+mod common;
+
+use common::{FakeReader, load, variable};
+use tasru::value::{Value, ValueOptions};
+
+#[test]
+fn variant_payloads_are_read() {
+    let info = load("enums");
+    let mut memory = FakeReader::from_fixture("enums");
+    let value = variable(&info, "SOME_NUMBER")
+        .read_value(&mut memory, ValueOptions::default())
+        .unwrap();
+    assert_eq!(value.to_string(), "Option<u32>::Some(195939070)");
+}
+
+#[test]
+fn errors_inside_a_value_become_leaves() {
+    let info = load("enums");
+    let some = variable(&info, "SOME_ENUM");
+    let mut memory = FakeReader::new();
+    // Neither variant of the inner enumeration has the discriminant 5.
+    memory.write(some.location().unwrap().0, &[5]);
+    let value = some
+        .read_value(&mut memory, ValueOptions::default())
+        .unwrap();
+    let Value::Enum { payload, .. } = value else {
+        panic!("not an enumeration: {value:?}");
+    };
+    let Some(Value::Struct { fields, .. }) = payload.as_deref() else {
+        panic!("no payload: {payload:?}");
+    };
+    assert!(
+        matches!(&fields[0].1, Value::Invalid(message) if message.contains("\"5\"")),
+        "{fields:?}"
+    );
+}